@@ -43,7 +43,7 @@
 // ► - **Economic Balance**: Gas costs vs reward amounts provide natural rate limiting
 // ══════════════════════════════════════════════════════════════════════════════
 
-use resolvers::{ResolverAttestationData, ResolverError, ResolverInterface, ResolverMetadata, ResolverType};
+use resolvers::{lifecycle, ResolverAttestationData, ResolverError, ResolverInterface, ResolverMetadata, ResolverType};
 use soroban_sdk::{contract, contractimpl, contracttype, token, Address, BytesN, Env, String};
 use stellar_macros::default_impl;
 use stellar_tokens::fungible::{Base, FungibleToken};
@@ -68,6 +68,9 @@ pub enum DataKey {
     Allowance,
 }
 
+/// Version of the storage layout this code expects, recorded by `migrate`
+pub const CONTRACT_VERSION: u32 = 1;
+
 #[contract]
 pub struct TokenRewardResolver;
 
@@ -115,6 +118,7 @@ impl TokenRewardResolver {
         env.storage().instance().set(&DataKey::RewardAmount, &reward_amount);
         env.storage().instance().set(&DataKey::TotalRewarded, &0i128);
         env.storage().instance().set(&DataKey::ProtocolContract, &protocol_contract);
+        lifecycle::set_version(&env, CONTRACT_VERSION);
         env.storage().instance().set(&DataKey::Initialized, &true);
 
         env.storage()
@@ -171,6 +175,7 @@ impl TokenRewardResolver {
         env.storage().instance().set(&DataKey::RewardAmount, &reward_amount);
         env.storage().instance().set(&DataKey::TotalRewarded, &0i128);
         env.storage().instance().set(&DataKey::ProtocolContract, &protocol_contract);
+        lifecycle::set_version(&env, CONTRACT_VERSION);
         env.storage().instance().set(&DataKey::Initialized, &true);
 
         env.storage()
@@ -219,6 +224,7 @@ impl TokenRewardResolver {
     /// enabling continued reward distribution.
     pub fn fund_reward_pool(env: Env, admin: Address, amount: i128) -> Result<(), ResolverError> {
         Self::require_admin(&env, &admin)?;
+        lifecycle::require_not_paused(&env)?;
 
         // Get reward token address
         let reward_token: Address = env
@@ -239,6 +245,55 @@ impl TokenRewardResolver {
         Ok(())
     }
 
+    /// Set or clear the address allowed to pause the resolver next to the admin (admin only)
+    pub fn set_pauser(env: Env, admin: Address, pauser: Option<Address>) -> Result<(), ResolverError> {
+        Self::require_admin(&env, &admin)?;
+        lifecycle::set_pauser(&env, pauser);
+        Ok(())
+    }
+
+    /// Pause the resolver hooks and fund transfers (admin or pauser)
+    pub fn pause(env: Env, caller: Address) -> Result<(), ResolverError> {
+        lifecycle::require_pauser(&env, &Self::get_admin(&env)?, &caller)?;
+        lifecycle::pause(&env, &caller)
+    }
+
+    /// Resume the resolver (admin or pauser)
+    pub fn unpause(env: Env, caller: Address) -> Result<(), ResolverError> {
+        lifecycle::require_pauser(&env, &Self::get_admin(&env)?, &caller)?;
+        lifecycle::unpause(&env, &caller)
+    }
+
+    /// Check whether the resolver is paused
+    pub fn is_paused(env: Env) -> bool {
+        lifecycle::is_paused(&env)
+    }
+
+    /// Replace the contract code with an uploaded WASM (admin only)
+    ///
+    /// Call `migrate` afterwards so the new code can update stored data.
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), ResolverError> {
+        Self::require_admin(&env, &admin)?;
+        lifecycle::upgrade(&env, new_wasm_hash);
+        Ok(())
+    }
+
+    /// Run the storage migrations of the current code, once per version (admin only)
+    ///
+    /// Version 1 added only the pause flag, the pauser and the version itself, all
+    /// read with defaults; reward totals and processed attestations keep the layout
+    /// of unversioned deployments, so migrating to it only records the version.
+    /// Returns the new version.
+    pub fn migrate(env: Env, admin: Address) -> Result<u32, ResolverError> {
+        Self::require_admin(&env, &admin)?;
+        lifecycle::migrate(&env, CONTRACT_VERSION)
+    }
+
+    /// Get the storage layout version, zero for deployments that predate versioning
+    pub fn get_version(env: Env) -> u32 {
+        lifecycle::get_version(&env)
+    }
+
     /// Get current reward pool balance
     pub fn get_pool_balance(env: Env) -> i128 {
         if let Some(reward_token) = env.storage().instance().get::<DataKey, Address>(&DataKey::RewardToken) {
@@ -252,19 +307,20 @@ impl TokenRewardResolver {
     fn require_admin(env: &Env, caller: &Address) -> Result<(), ResolverError> {
         caller.require_auth();
 
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(ResolverError::CustomError)?;
-
-        if caller != &admin {
+        if caller != &Self::get_admin(env)? {
             return Err(ResolverError::NotAuthorized);
         }
 
         Ok(())
     }
 
+    fn get_admin(env: &Env) -> Result<Address, ResolverError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(ResolverError::CustomError)
+    }
+
     /// Extends the TTL of instance storage to prevent expiration.
     /// Should be called on any method that relies on instance storage.
     fn extend_instance_ttl(env: &Env) {
@@ -302,12 +358,15 @@ impl ResolverInterface for TokenRewardResolver {
     /// - Quality scoring mechanisms
     ///
     /// # Parameters
-    /// * `_env` - Soroban environment (unused in permissionless model)
+    /// * `env` - Soroban environment, used for the pause check
     /// * `_attestation` - ResolverAttestationData (no validation performed)
     ///
     /// # Returns
-    /// * `Ok(true)` - Always allows attestations (permissionless access)
-    fn onattest(_env: Env, _attestation: ResolverAttestationData) -> Result<bool, ResolverError> {
+    /// * `Ok(true)` - Allows all attestations while the resolver is not paused
+    /// * `Err(ResolverError::Paused)` - Resolver is paused
+    fn onattest(env: Env, _attestation: ResolverAttestationData) -> Result<bool, ResolverError> {
+        lifecycle::require_not_paused(&env)?;
+
         // PERMISSIONLESS MODEL: Allow all attestations
         // Economic incentives through token rewards drive participation
         // Gas costs provide natural spam resistance
@@ -346,6 +405,7 @@ impl ResolverInterface for TokenRewardResolver {
     /// * `Ok(())` - Reward distributed successfully
     /// * `Err(ResolverError::CustomError)` - Reward token not configured
     /// * `Err(ResolverError::InsufficientFunds)` - Contract balance too low
+    /// * `Err(ResolverError::Paused)` - Resolver is paused
     ///
     /// # Critical Security Properties
     /// - **Authorization**: Only contract can initiate token transfers from its balance
@@ -377,6 +437,7 @@ impl ResolverInterface for TokenRewardResolver {
             .get(&DataKey::ProtocolContract)
             .ok_or(ResolverError::CustomError)?;
         protocol_contract.require_auth();
+        lifecycle::require_not_paused(&env)?;
 
         // STEP 0b: Check for replay attack - ensure this attestation hasn't been rewarded
         let processed_key = (DataKey::ProcessedAttestation, attestation_uid.clone());
@@ -5,8 +5,8 @@ use soroban_sdk::{
     token, Address, Bytes, BytesN, Env,
 };
 
-use airdrop::{TokenRewardResolver, TokenRewardResolverClient};
-use resolvers::{ResolverAttestationData, ResolverType};
+use airdrop::{TokenRewardResolver, TokenRewardResolverClient, CONTRACT_VERSION};
+use resolvers::{lifecycle::LifecycleKey, ResolverAttestationData, ResolverError, ResolverType};
 
 const REWARD_AMOUNT: i128 = 10_0000000; // 10 tokens per attestation
 
@@ -374,3 +374,93 @@ fn test_multiple_attestations_multiple_users() {
     assert_eq!(client.get_user_rewards(&user3), REWARD_AMOUNT);
     assert_eq!(client.get_total_rewarded(), REWARD_AMOUNT * 3);
 }
+
+// ============================================================================
+// Pause Tests
+// ============================================================================
+
+#[test]
+fn test_pause_blocks_hooks_and_funding() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = TokenRewardResolverClient::new(env, &setup.contract_id);
+
+    let token_admin = token::StellarAssetClient::new(env, &setup.reward_token);
+    let fund_amount: i128 = 1000_0000000;
+    token_admin.mint(&setup.admin, &(fund_amount * 2));
+    client.fund_reward_pool(&setup.admin, &fund_amount);
+
+    client.pause(&setup.admin);
+    assert!(client.is_paused());
+
+    let attester = Address::generate(env);
+    let result = client.try_onattest(&build_attestation(env, &attester));
+    assert_eq!(result.err().unwrap(), Ok(ResolverError::Paused));
+    let result = client.try_onresolve(&BytesN::random(env), &attester);
+    assert_eq!(result.err().unwrap(), Ok(ResolverError::Paused));
+    let result = client.try_fund_reward_pool(&setup.admin, &fund_amount);
+    assert_eq!(result.err().unwrap(), Ok(ResolverError::Paused));
+
+    // Getters keep working
+    assert_eq!(client.get_pool_balance(), fund_amount);
+    assert_eq!(client.get_user_rewards(&attester), 0);
+
+    client.unpause(&setup.admin);
+    client.onresolve(&BytesN::random(env), &attester);
+    assert_eq!(client.get_user_rewards(&attester), REWARD_AMOUNT);
+}
+
+#[test]
+fn test_pauser_can_pause_and_unpause() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = TokenRewardResolverClient::new(env, &setup.contract_id);
+    let pauser = Address::generate(env);
+
+    let result = client.try_pause(&pauser);
+    assert_eq!(result.err().unwrap(), Ok(ResolverError::NotAuthorized));
+
+    client.set_pauser(&setup.admin, &Some(pauser.clone()));
+    client.pause(&pauser);
+    assert!(client.is_paused());
+    client.unpause(&pauser);
+    assert!(!client.is_paused());
+    let result = client.try_unpause(&pauser);
+    assert_eq!(result.err().unwrap(), Ok(ResolverError::NotPaused));
+
+    let result = client.try_set_pauser(&pauser, &Some(pauser.clone()));
+    assert_eq!(result.err().unwrap(), Ok(ResolverError::NotAuthorized));
+}
+
+// ============================================================================
+// Upgrade Tests
+// ============================================================================
+
+#[test]
+fn test_upgrade_non_admin_fails() {
+    let setup = setup_env();
+    let client = TokenRewardResolverClient::new(&setup.env, &setup.contract_id);
+
+    let non_admin = Address::generate(&setup.env);
+    let result = client.try_upgrade(&non_admin, &BytesN::random(&setup.env));
+    assert_eq!(result.err().unwrap(), Ok(ResolverError::NotAuthorized));
+}
+
+#[test]
+fn test_migrate_runs_once_per_version() {
+    let setup = setup_env();
+    let client = TokenRewardResolverClient::new(&setup.env, &setup.contract_id);
+
+    assert_eq!(client.get_version(), CONTRACT_VERSION);
+    let result = client.try_migrate(&setup.admin);
+    assert_eq!(result.err().unwrap(), Ok(ResolverError::AlreadyMigrated));
+
+    // Simulate a deployment that predates versioning
+    setup.env.as_contract(&setup.contract_id, || {
+        setup.env.storage().instance().remove(&LifecycleKey::Version);
+    });
+    assert_eq!(client.get_version(), 0);
+
+    assert_eq!(client.migrate(&setup.admin), CONTRACT_VERSION);
+    assert_eq!(client.get_version(), CONTRACT_VERSION);
+}
@@ -9,18 +9,25 @@
 // a single account (the "owner") has exclusive access to specific functions.
 
 use crate::errors::Error;
-use crate::state::{get_admin, is_initialized, set_admin};
+use crate::state::{
+    get_admin, get_pending_owner as read_pending_owner, is_initialized, is_renounced, is_role_member, remove_admin,
+    remove_pending_owner, remove_role_member, set_admin, set_pending_owner, set_renounced, set_role_member,
+    PendingOwnership, Role,
+};
 use soroban_sdk::{Address, Env, String};
 
 // ══════════════════════════════════════════════════════════════════════════════
 // ► Core Access Control Functions
 // ══════════════════════════════════════════════════════════════════════════════
 
+/// Placeholder owner written by earlier versions of `renounce_ownership`
+const LEGACY_RENOUNCED_OWNER: &str = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
+
 /// Returns the current owner of the contract
 ///
 /// # Returns
 /// * `Some(Address)` - The owner address if set
-/// * `None` - If no owner is set (contract not initialized)
+/// * `None` - If no owner is set (contract not initialized or ownership renounced)
 pub fn owner(env: &Env) -> Option<Address> {
     get_admin(env)
 }
@@ -80,6 +87,11 @@ pub fn only_owner(env: &Env, caller: &Address) -> Result<(), Error> {
         return Err(Error::NotInitialized);
     }
 
+    // A renounced contract has no owner
+    if is_renounced(env) {
+        return Err(Error::OwnershipRenounced);
+    }
+
     // Check if caller is the owner
     if !is_owner(env, caller) {
         return Err(Error::NotAuthorized);
@@ -92,32 +104,107 @@ pub fn only_owner(env: &Env, caller: &Address) -> Result<(), Error> {
 // ► Ownership Transfer Functions
 // ══════════════════════════════════════════════════════════════════════════════
 
-/// Transfers ownership of the contract to a new account
+/// Starts a two-step ownership transfer by offering ownership to a new account
 ///
 /// # Arguments
 /// * `env` - The Soroban environment
 /// * `current_owner` - The current owner address (must match actual owner)
-/// * `new_owner` - The address to transfer ownership to
+/// * `new_owner` - The address that may accept ownership
+/// * `live_until_ledger` - Last ledger sequence at which the offer can be accepted
 ///
 /// # Returns
-/// * `Ok(())` - If ownership transfer is successful
+/// * `Ok(())` - If the offer is recorded
+/// * `Err(Error::InvalidLiveUntilLedger)` - If the expiry is in the past or beyond the max entry TTL
 /// * `Err(Error)` - If not authorized or contract not initialized
 ///
 /// # Security Notes
-/// * Only the current owner can transfer ownership
-/// * Immediately transfers ownership (no two-step process for simplicity)
-/// * Use with caution - ownership cannot be recovered if transferred to wrong address
-pub fn transfer_ownership(env: &Env, current_owner: &Address, new_owner: &Address) -> Result<(), Error> {
+/// * Only the current owner can start a transfer
+/// * Ownership does not change until `new_owner` calls `accept_ownership`, so a
+///   mistyped address can never take control
+/// * A new offer replaces any pending one
+pub fn transfer_ownership(
+    env: &Env,
+    current_owner: &Address,
+    new_owner: &Address,
+    live_until_ledger: u32,
+) -> Result<(), Error> {
     only_owner(env, current_owner)?;
 
+    let current_ledger = env.ledger().sequence();
+    if live_until_ledger < current_ledger || live_until_ledger > env.ledger().max_live_until_ledger() {
+        return Err(Error::InvalidLiveUntilLedger);
+    }
+
+    let pending = PendingOwnership {
+        new_owner: new_owner.clone(),
+        live_until_ledger,
+    };
+    set_pending_owner(env, &pending);
+
+    crate::events::ownership_transfer_started(env, current_owner, new_owner, live_until_ledger);
+
+    Ok(())
+}
+
+/// Completes a two-step ownership transfer
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `new_owner` - The pending owner (must be authenticated)
+///
+/// # Returns
+/// * `Ok(())` - If ownership was transferred
+/// * `Err(Error::NoPendingOwnershipTransfer)` - If no transfer is pending
+/// * `Err(Error::NotAuthorized)` - If the caller is not the pending owner
+/// * `Err(Error::OwnershipTransferExpired)` - If the offer's ledger expiry has passed
+pub fn accept_ownership(env: &Env, new_owner: &Address) -> Result<(), Error> {
+    new_owner.require_auth();
+
+    let pending = read_pending_owner(env).ok_or(Error::NoPendingOwnershipTransfer)?;
+    if pending.new_owner != *new_owner {
+        return Err(Error::NotAuthorized);
+    }
+    if env.ledger().sequence() > pending.live_until_ledger {
+        return Err(Error::OwnershipTransferExpired);
+    }
+
+    let previous_owner = get_owner(env)?;
     set_owner(env, new_owner);
+    remove_pending_owner(env);
 
     // Emit ownership transfer event
-    crate::events::ownership_transferred(env, current_owner, new_owner);
+    crate::events::ownership_transferred(env, &previous_owner, new_owner);
 
     Ok(())
 }
 
+/// Cancels a pending ownership transfer
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `current_owner` - The current owner address (must match actual owner)
+///
+/// # Returns
+/// * `Ok(())` - If the pending offer was removed
+/// * `Err(Error::NoPendingOwnershipTransfer)` - If no transfer is pending
+pub fn cancel_ownership_transfer(env: &Env, current_owner: &Address) -> Result<(), Error> {
+    only_owner(env, current_owner)?;
+
+    let pending = read_pending_owner(env).ok_or(Error::NoPendingOwnershipTransfer)?;
+    remove_pending_owner(env);
+
+    crate::events::ownership_transfer_cancelled(env, current_owner, &pending.new_owner);
+
+    Ok(())
+}
+
+/// Returns the pending owner, or `None` if no unexpired transfer is pending
+pub fn pending_owner(env: &Env) -> Option<Address> {
+    read_pending_owner(env)
+        .filter(|pending| env.ledger().sequence() <= pending.live_until_ledger)
+        .map(|pending| pending.new_owner)
+}
+
 /// Renounces ownership of the contract
 ///
 /// # Arguments
@@ -130,16 +217,17 @@ pub fn transfer_ownership(env: &Env, current_owner: &Address, new_owner: &Addres
 ///
 /// # Warning
 /// This is a one-way operation that cannot be undone! After renouncing ownership:
-/// * All owner-only functions become permanently inaccessible
-/// * The contract cannot be upgraded or administered
+/// * The owner is removed and the `Renounced` flag is set
+/// * All owner-only functions fail with `Error::OwnershipRenounced`
+/// * Any pending ownership transfer is discarded
+/// * The contract cannot be upgraded, migrated or administered
 /// * Use only when you want to make the contract fully decentralized
 pub fn renounce_ownership(env: &Env, current_owner: &Address) -> Result<(), Error> {
     only_owner(env, current_owner)?;
 
-    // Set owner to a zero-like address (using first valid Stellar address format)
-    let zero_address_str = String::from_str(env, "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF");
-    let zero_address = Address::from_string(&zero_address_str);
-    set_owner(env, &zero_address);
+    remove_admin(env);
+    remove_pending_owner(env);
+    set_renounced(env);
 
     // Emit ownership renunciation event
     crate::events::ownership_renounced(env, current_owner);
@@ -147,6 +235,166 @@ pub fn renounce_ownership(env: &Env, current_owner: &Address) -> Result<(), Erro
     Ok(())
 }
 
+/// Converts a renunciation made by earlier contract versions into the explicit state
+///
+/// Earlier versions renounced by storing the `GAAAA...WHF` placeholder as owner.
+/// Anyone may call this; it only acts when that placeholder is the stored owner.
+///
+/// # Returns
+/// * `Ok(true)` - If the placeholder owner was replaced by the `Renounced` flag
+/// * `Ok(false)` - If there was nothing to migrate
+/// * `Err(Error::NotInitialized)` - If the contract is not initialized
+pub fn migrate_renounced_ownership(env: &Env) -> Result<bool, Error> {
+    if !is_initialized(env) {
+        return Err(Error::NotInitialized);
+    }
+
+    let legacy_owner = Address::from_string(&String::from_str(env, LEGACY_RENOUNCED_OWNER));
+    if owner(env) != Some(legacy_owner.clone()) {
+        return Ok(false);
+    }
+
+    remove_admin(env);
+    remove_pending_owner(env);
+    set_renounced(env);
+
+    crate::events::ownership_renounce_migrated(env, &legacy_owner);
+
+    Ok(true)
+}
+
+/// True once ownership has been renounced
+pub fn is_ownership_renounced(env: &Env) -> bool {
+    is_renounced(env)
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Role Functions
+// ══════════════════════════════════════════════════════════════════════════════
+
+/// Checks if an account holds a role explicitly
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `role` - The role to check
+/// * `account` - The address to check
+///
+/// # Returns
+/// * `true` - If the role was granted to the account and not revoked
+/// * `false` - Otherwise; the owner and super-admins are not reported as members
+pub fn has_role(env: &Env, role: Role, account: &Address) -> bool {
+    is_role_member(env, role, account)
+}
+
+/// Modifier-like function that requires the caller to be allowed to act as `role`
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `caller` - The address attempting to call the function
+/// * `role` - The role the function requires
+///
+/// # Returns
+/// * `Ok(())` - If the caller is the owner, a super-admin or holds `role`
+/// * `Err(Error::OwnershipRenounced)` - If ownership was renounced; renouncing closes every admin path
+/// * `Err(Error)` - If the caller lacks the role or contract not initialized
+pub fn require_role(env: &Env, caller: &Address, role: Role) -> Result<(), Error> {
+    caller.require_auth();
+
+    if !is_initialized(env) {
+        return Err(Error::NotInitialized);
+    }
+    if is_renounced(env) {
+        return Err(Error::OwnershipRenounced);
+    }
+
+    if is_owner(env, caller) || is_role_member(env, Role::SuperAdmin, caller) || is_role_member(env, role, caller) {
+        Ok(())
+    } else {
+        Err(Error::NotAuthorized)
+    }
+}
+
+/// Grants a role to an account
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `caller` - The owner, or a super-admin for roles other than `SuperAdmin`
+/// * `role` - The role to grant
+/// * `account` - The address receiving the role
+///
+/// # Returns
+/// * `Ok(())` - If the role was granted
+/// * `Err(Error::RoleAlreadyGranted)` - If the account already holds the role
+/// * `Err(Error)` - If not authorized or contract not initialized
+pub fn grant_role(env: &Env, caller: &Address, role: Role, account: &Address) -> Result<(), Error> {
+    require_role_admin(env, caller, role)?;
+
+    if is_role_member(env, role, account) {
+        return Err(Error::RoleAlreadyGranted);
+    }
+    set_role_member(env, role, account);
+
+    crate::events::role_granted(env, role, account, caller);
+
+    Ok(())
+}
+
+/// Revokes a role from an account
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `caller` - The owner, or a super-admin for roles other than `SuperAdmin`
+/// * `role` - The role to revoke
+/// * `account` - The address losing the role
+///
+/// # Returns
+/// * `Ok(())` - If the role was revoked
+/// * `Err(Error::RoleNotGranted)` - If the account does not hold the role
+/// * `Err(Error)` - If not authorized or contract not initialized
+pub fn revoke_role(env: &Env, caller: &Address, role: Role, account: &Address) -> Result<(), Error> {
+    require_role_admin(env, caller, role)?;
+
+    if !is_role_member(env, role, account) {
+        return Err(Error::RoleNotGranted);
+    }
+    remove_role_member(env, role, account);
+
+    crate::events::role_revoked(env, role, account, caller);
+
+    Ok(())
+}
+
+/// Gives up a role held by the caller
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `account` - The role holder (must be authenticated)
+/// * `role` - The role to give up
+///
+/// # Returns
+/// * `Ok(())` - If the role was removed
+/// * `Err(Error::RoleNotGranted)` - If the account does not hold the role
+pub fn renounce_role(env: &Env, account: &Address, role: Role) -> Result<(), Error> {
+    account.require_auth();
+
+    if !is_role_member(env, role, account) {
+        return Err(Error::RoleNotGranted);
+    }
+    remove_role_member(env, role, account);
+
+    crate::events::role_renounced(env, role, account);
+
+    Ok(())
+}
+
+/// Only the owner manages super-admins; the owner and super-admins manage the other roles
+fn require_role_admin(env: &Env, caller: &Address, role: Role) -> Result<(), Error> {
+    match role {
+        Role::SuperAdmin => only_owner(env, caller),
+        _ => require_role(env, caller, Role::SuperAdmin),
+    }
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // ► Convenience Functions
 // ══════════════════════════════════════════════════════════════════════════════
@@ -158,8 +406,12 @@ pub fn renounce_ownership(env: &Env, current_owner: &Address) -> Result<(), Erro
 ///
 /// # Returns
 /// * `Ok(Address)` - The owner address
+/// * `Err(Error::OwnershipRenounced)` - If ownership was renounced
 /// * `Err(Error::NotInitialized)` - If no owner is set
 pub fn get_owner(env: &Env) -> Result<Address, Error> {
+    if is_renounced(env) {
+        return Err(Error::OwnershipRenounced);
+    }
     owner(env).ok_or(Error::NotInitialized)
 }
 
@@ -168,6 +420,7 @@ pub fn get_owner(env: &Env) -> Result<Address, Error> {
 // ══════════════════════════════════════════════════════════════════════════════
 
 #[cfg(test)]
+#[allow(dead_code)]
 pub mod test_utils {
     use super::*;
     use soroban_sdk::testutils::Address as _;
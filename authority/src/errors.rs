@@ -26,4 +26,61 @@ pub enum Error {
     InvalidVerificationLevel = 15,
     VerifierNotFound = 16,
     InvalidAuthorityData = 17,
+    InvalidFeeAmount = 18,
+    InvalidFeeToken = 19, // Payment attempted with a token other than the configured TokenId
+    WithdrawalExceedsBalance = 20,
+
+    // Authority Lifecycle Errors
+    AuthorityNotFound = 21,
+    InvalidStatusTransition = 22,
+    AuthorityNotActive = 23, // Authority is suspended or revoked
+    AuthorityExpired = 24,   // Registration lapsed, renewal required
+    InvalidValidityPeriod = 25,
+    RenewalNotRequired = 26, // Registration is permanent
+
+    // Schema Rule Errors
+    SchemaNotRevocable = 27,
+    InvalidExpiration = 28, // Expiration missing or beyond the schema's window
+
+    // Levy Errors
+    InvalidProtocolShare = 29, // Protocol share above 10_000 basis points
+
+    // Ownership Errors
+    NoPendingOwnershipTransfer = 30,
+    OwnershipTransferExpired = 31,
+    InvalidLiveUntilLedger = 32, // Offer expiry in the past or beyond the max entry TTL
+    OwnershipRenounced = 33,     // Contract has no owner; owner-only paths are closed
+
+    // Role Errors
+    RoleAlreadyGranted = 34,
+    RoleNotGranted = 35,
+
+    // Pause Errors
+    ContractPaused = 36,    // Hooks and fund-moving functions are stopped
+    ContractNotPaused = 37, // Unpause called on a running contract
+
+    // Upgrade Errors
+    AlreadyMigrated = 38,        // Storage is already at the version of the running code
+    MigrationBatchTooLarge = 39, // More addresses than `MAX_MIGRATION_BATCH`
+
+    // Payment Errors
+    PaymentNotFound = 40,
+    PaymentAlreadyRefunded = 41,
+    PaymentAlreadyUsed = 42, // The payer is a registered authority; its payment cannot be refunded
+    InvalidPaymentTransition = 43, // Only pending payments can be approved or rejected
+
+    // Nomination Errors
+    NominationNotFound = 44,
+    NominationPending = 45,    // The nominee already has an unexpired nomination
+    NominationExpired = 46,    // The acceptance window has passed; the sponsor can be refunded
+    NominationNotExpired = 47, // The nominee can still accept
+
+    // Profile Errors
+    InvalidAuthorityProfile = 48, // Empty name or a field longer than `MAX_PROFILE_FIELD_LEN`
+
+    // Protocol Errors
+    ProtocolNotConfigured = 49, // No protocol contract is set; hooks cannot be called
+
+    // Renewal Errors
+    VerificationExpired = 50, // The attested verification ends by the current expiry; a new attestation is required
 }
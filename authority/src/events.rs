@@ -5,18 +5,48 @@ use soroban_sdk::{symbol_short, Env, Symbol};
 // ══════════════════════════════════════════════════════════════════════════════
 pub const ADMIN_REG_AUTH: Symbol = symbol_short!("adm_rg_at");
 pub const AUTHORITY_REGISTERED: Symbol = symbol_short!("auth_reg");
+pub const AUTHORITY_SUSPENDED: Symbol = symbol_short!("auth_susp");
+pub const AUTHORITY_REINSTATED: Symbol = symbol_short!("auth_rein");
+pub const AUTHORITY_REVOKED: Symbol = symbol_short!("auth_rvkd");
+pub const AUTHORITY_RENEWED: Symbol = symbol_short!("auth_renw");
+pub const AUTHORITY_LEVEL_UPDATED: Symbol = symbol_short!("auth_lvl");
+pub const AUTHORITY_PROFILE_UPDATED: Symbol = symbol_short!("auth_prof");
+pub const AUTHORITY_NOMINATED: Symbol = symbol_short!("auth_nom");
+pub const NOMINATION_ACCEPTED: Symbol = symbol_short!("nom_acpt");
+pub const NOMINATION_EXPIRED: Symbol = symbol_short!("nom_exp");
 pub const SCHEMA_REGISTERED: Symbol = symbol_short!("schm_reg");
+pub const SCHEMA_LEVEL_UPDATED: Symbol = symbol_short!("schm_lvl");
 pub const LEVY_COLLECTED: Symbol = symbol_short!("levy_coll");
 pub const LEVY_WITHDRAWN: Symbol = symbol_short!("levy_wdrw");
+pub const LEVY_SHARE_UPDATED: Symbol = symbol_short!("levy_shr");
+pub const FEES_WITHDRAWN: Symbol = symbol_short!("fees_wdrw");
 pub const OWNERSHIP_TRANSFERRED: Symbol = symbol_short!("own_trans");
+pub const OWNERSHIP_TRANSFER_STARTED: Symbol = symbol_short!("own_start");
+pub const OWNERSHIP_TRANSFER_CANCELLED: Symbol = symbol_short!("own_cncl");
 pub const OWNERSHIP_RENOUNCED: Symbol = symbol_short!("own_rncd");
+pub const ROLE_GRANTED: Symbol = symbol_short!("role_grnt");
+pub const ROLE_REVOKED: Symbol = symbol_short!("role_rvkd");
+pub const CONTRACT_PAUSED: Symbol = symbol_short!("paused");
+pub const CONTRACT_UNPAUSED: Symbol = symbol_short!("unpaused");
+pub const CONTRACT_UPGRADED: Symbol = symbol_short!("upgraded");
+pub const CONTRACT_MIGRATED: Symbol = symbol_short!("migrated");
+pub const LEGACY_MIGRATED: Symbol = symbol_short!("legacy_mg");
+pub const PROTOCOL_UPDATED: Symbol = symbol_short!("proto_upd");
+pub const ATTESTER_ADDED: Symbol = symbol_short!("att_add");
+pub const ATTESTER_REMOVED: Symbol = symbol_short!("att_rmvd");
 pub const PAYMENT_RECEIVED: Symbol = symbol_short!("pay_rcvd");
+pub const PAYMENT_REFUNDED: Symbol = symbol_short!("pay_rfnd");
+pub const PAYMENT_APPROVED: Symbol = symbol_short!("pay_aprv");
+pub const PAYMENT_REJECTED: Symbol = symbol_short!("pay_rjct");
+pub const REGISTRATION_FEE_UPDATED: Symbol = symbol_short!("fee_updt");
+pub const FEE_TOKEN_UPDATED: Symbol = symbol_short!("fee_tkn");
+pub const VALIDITY_UPDATED: Symbol = symbol_short!("valid_upd");
 
 // Helper functions to publish events with appropriate topics and data
-pub fn admin_register_authority(e: &Env, authority: &soroban_sdk::Address, metadata: &soroban_sdk::String) {
+pub fn admin_register_authority(e: &Env, authority: &soroban_sdk::Address, profile: &crate::state::AuthorityProfile) {
     e.events().publish(
         (ADMIN_REG_AUTH, symbol_short!("register")),
-        (authority.clone(), metadata.clone()),
+        (authority.clone(), profile.clone()),
     );
 }
 
@@ -24,30 +54,198 @@ pub fn authority_registered(
     e: &Env,
     caller: &soroban_sdk::Address,
     authority: &soroban_sdk::Address,
-    metadata: &soroban_sdk::String,
+    profile: &crate::state::AuthorityProfile,
 ) {
     e.events().publish(
         (AUTHORITY_REGISTERED, symbol_short!("register")),
-        (caller.clone(), authority.clone(), metadata.clone()),
+        (caller.clone(), authority.clone(), profile.clone()),
+    );
+}
+
+pub fn authority_profile_updated(
+    e: &Env,
+    authority: &soroban_sdk::Address,
+    caller: &soroban_sdk::Address,
+    profile_version: u32,
+) {
+    e.events().publish(
+        (AUTHORITY_PROFILE_UPDATED, symbol_short!("update")),
+        (authority.clone(), caller.clone(), profile_version),
+    );
+}
+
+pub fn authority_nominated(e: &Env, nomination: &crate::state::AuthorityNomination) {
+    e.events().publish(
+        (AUTHORITY_NOMINATED, symbol_short!("nominate")),
+        (
+            nomination.sponsor.clone(),
+            nomination.nominee.clone(),
+            nomination.profile.clone(),
+            nomination.token.clone(),
+            nomination.amount_paid,
+            nomination.expires_at,
+        ),
+    );
+}
+
+pub fn nomination_accepted(e: &Env, sponsor: &soroban_sdk::Address, nominee: &soroban_sdk::Address) {
+    e.events().publish(
+        (NOMINATION_ACCEPTED, symbol_short!("nominate")),
+        (sponsor.clone(), nominee.clone()),
+    );
+}
+
+pub fn nomination_expired(e: &Env, nomination: &crate::state::AuthorityNomination) {
+    e.events().publish(
+        (NOMINATION_EXPIRED, symbol_short!("nominate")),
+        (
+            nomination.sponsor.clone(),
+            nomination.nominee.clone(),
+            nomination.token.clone(),
+            nomination.amount_paid,
+        ),
+    );
+}
+
+pub fn authority_suspended(
+    e: &Env,
+    authority: &soroban_sdk::Address,
+    changed_by: &soroban_sdk::Address,
+    reason: &soroban_sdk::String,
+) {
+    e.events().publish(
+        (AUTHORITY_SUSPENDED, symbol_short!("suspend")),
+        (authority.clone(), changed_by.clone(), reason.clone()),
+    );
+}
+
+pub fn authority_reinstated(
+    e: &Env,
+    authority: &soroban_sdk::Address,
+    changed_by: &soroban_sdk::Address,
+    reason: &soroban_sdk::String,
+) {
+    e.events().publish(
+        (AUTHORITY_REINSTATED, symbol_short!("reinstate")),
+        (authority.clone(), changed_by.clone(), reason.clone()),
+    );
+}
+
+pub fn authority_revoked(
+    e: &Env,
+    authority: &soroban_sdk::Address,
+    changed_by: &soroban_sdk::Address,
+    reason: &soroban_sdk::String,
+) {
+    e.events().publish(
+        (AUTHORITY_REVOKED, symbol_short!("revoke")),
+        (authority.clone(), changed_by.clone(), reason.clone()),
+    );
+}
+
+pub fn authority_renewed(e: &Env, authority: &soroban_sdk::Address, expires_at: u64, amount: i128) {
+    e.events().publish(
+        (AUTHORITY_RENEWED, symbol_short!("renew")),
+        (authority.clone(), expires_at, amount),
+    );
+}
+
+pub fn authority_level_updated(
+    e: &Env,
+    authority: &soroban_sdk::Address,
+    changed_by: &soroban_sdk::Address,
+    level: crate::state::VerificationLevel,
+) {
+    e.events().publish(
+        (AUTHORITY_LEVEL_UPDATED, symbol_short!("update")),
+        (authority.clone(), changed_by.clone(), level),
+    );
+}
+
+pub fn schema_level_updated(
+    e: &Env,
+    schema_uid: &soroban_sdk::BytesN<32>,
+    changed_by: &soroban_sdk::Address,
+    level: crate::state::VerificationLevel,
+) {
+    e.events().publish(
+        (SCHEMA_LEVEL_UPDATED, symbol_short!("update")),
+        (schema_uid.clone(), changed_by.clone(), level),
+    );
+}
+
+pub fn schema_registered(e: &Env, schema_uid: &soroban_sdk::BytesN<32>, rules: &crate::state::SchemaRules) {
+    e.events().publish(
+        (SCHEMA_REGISTERED, symbol_short!("register")),
+        (schema_uid.clone(), rules.clone()),
     );
 }
 
 pub fn levy_collected(
     e: &Env,
-    attester: &soroban_sdk::Address,
+    payer: &soroban_sdk::Address,
     recipient: &soroban_sdk::Address,
     schema_uid: &soroban_sdk::BytesN<32>,
     amount: i128,
+    protocol_cut: i128,
 ) {
     e.events().publish(
         (LEVY_COLLECTED, symbol_short!("collect")),
-        (attester.clone(), recipient.clone(), schema_uid.clone(), amount),
+        (
+            payer.clone(),
+            recipient.clone(),
+            schema_uid.clone(),
+            amount,
+            protocol_cut,
+        ),
     );
 }
 
-pub fn levy_withdrawn(e: &Env, recipient: &soroban_sdk::Address, amount: i128) {
-    e.events()
-        .publish((LEVY_WITHDRAWN, symbol_short!("withdraw")), (recipient.clone(), amount));
+pub fn levy_share_updated(e: &Env, admin: &soroban_sdk::Address, share_bps: u32) {
+    e.events().publish(
+        (LEVY_SHARE_UPDATED, symbol_short!("update")),
+        (admin.clone(), share_bps),
+    );
+}
+
+pub fn protocol_updated(
+    e: &Env,
+    admin: &soroban_sdk::Address,
+    previous: &Option<soroban_sdk::Address>,
+    protocol: &soroban_sdk::Address,
+) {
+    e.events().publish(
+        (PROTOCOL_UPDATED, symbol_short!("update")),
+        (admin.clone(), previous.clone(), protocol.clone()),
+    );
+}
+
+pub fn attester_added(e: &Env, admin: &soroban_sdk::Address, attester: &soroban_sdk::Address) {
+    e.events().publish(
+        (ATTESTER_ADDED, symbol_short!("attester")),
+        (admin.clone(), attester.clone()),
+    );
+}
+
+pub fn attester_removed(e: &Env, admin: &soroban_sdk::Address, attester: &soroban_sdk::Address) {
+    e.events().publish(
+        (ATTESTER_REMOVED, symbol_short!("attester")),
+        (admin.clone(), attester.clone()),
+    );
+}
+
+pub fn levy_withdrawn(e: &Env, recipient: &soroban_sdk::Address, token: &soroban_sdk::Address, amount: i128) {
+    e.events().publish(
+        (LEVY_WITHDRAWN, symbol_short!("withdraw")),
+        (recipient.clone(), token.clone(), amount),
+    );
+}
+
+pub fn admin_fees_withdrawn(e: &Env, admin: &soroban_sdk::Address, token_address: &soroban_sdk::Address, amount: i128) {
+    e.events().publish(
+        (FEES_WITHDRAWN, symbol_short!("admin")),
+        (admin.clone(), token_address.clone(), amount),
+    );
 }
 
 pub fn ownership_transferred(e: &Env, previous_owner: &soroban_sdk::Address, new_owner: &soroban_sdk::Address) {
@@ -57,14 +255,160 @@ pub fn ownership_transferred(e: &Env, previous_owner: &soroban_sdk::Address, new
     );
 }
 
+pub fn ownership_transfer_started(
+    e: &Env,
+    current_owner: &soroban_sdk::Address,
+    pending_owner: &soroban_sdk::Address,
+    live_until_ledger: u32,
+) {
+    e.events().publish(
+        (OWNERSHIP_TRANSFER_STARTED, symbol_short!("start")),
+        (current_owner.clone(), pending_owner.clone(), live_until_ledger),
+    );
+}
+
+pub fn ownership_transfer_cancelled(
+    e: &Env,
+    current_owner: &soroban_sdk::Address,
+    pending_owner: &soroban_sdk::Address,
+) {
+    e.events().publish(
+        (OWNERSHIP_TRANSFER_CANCELLED, symbol_short!("cancel")),
+        (current_owner.clone(), pending_owner.clone()),
+    );
+}
+
 pub fn ownership_renounced(e: &Env, previous_owner: &soroban_sdk::Address) {
     e.events()
         .publish((OWNERSHIP_RENOUNCED, symbol_short!("renounce")), previous_owner.clone());
 }
 
+pub fn ownership_renounce_migrated(e: &Env, legacy_owner: &soroban_sdk::Address) {
+    e.events()
+        .publish((OWNERSHIP_RENOUNCED, symbol_short!("migrate")), legacy_owner.clone());
+}
+
+pub fn role_granted(e: &Env, role: crate::state::Role, account: &soroban_sdk::Address, sender: &soroban_sdk::Address) {
+    e.events().publish(
+        (ROLE_GRANTED, symbol_short!("grant")),
+        (role, account.clone(), sender.clone()),
+    );
+}
+
+pub fn role_revoked(e: &Env, role: crate::state::Role, account: &soroban_sdk::Address, sender: &soroban_sdk::Address) {
+    e.events().publish(
+        (ROLE_REVOKED, symbol_short!("revoke")),
+        (role, account.clone(), sender.clone()),
+    );
+}
+
+pub fn role_renounced(e: &Env, role: crate::state::Role, account: &soroban_sdk::Address) {
+    e.events()
+        .publish((ROLE_REVOKED, symbol_short!("renounce")), (role, account.clone()));
+}
+
+pub fn contract_paused(e: &Env, caller: &soroban_sdk::Address) {
+    e.events()
+        .publish((CONTRACT_PAUSED, symbol_short!("pause")), caller.clone());
+}
+
+pub fn contract_unpaused(e: &Env, caller: &soroban_sdk::Address) {
+    e.events()
+        .publish((CONTRACT_UNPAUSED, symbol_short!("unpause")), caller.clone());
+}
+
+pub fn contract_upgraded(
+    e: &Env,
+    admin: &soroban_sdk::Address,
+    new_wasm_hash: &soroban_sdk::BytesN<32>,
+    current_version: u32,
+) {
+    e.events().publish(
+        (CONTRACT_UPGRADED, symbol_short!("upgrade")),
+        (admin.clone(), new_wasm_hash.clone(), current_version),
+    );
+}
+
+pub fn contract_migrated(e: &Env, admin: &soroban_sdk::Address, from_version: u32, to_version: u32) {
+    e.events().publish(
+        (CONTRACT_MIGRATED, symbol_short!("migrate")),
+        (admin.clone(), from_version, to_version),
+    );
+}
+
+pub fn legacy_entries_migrated(e: &Env, admin: &soroban_sdk::Address, migrated: u32) {
+    e.events()
+        .publish((LEGACY_MIGRATED, symbol_short!("migrate")), (admin.clone(), migrated));
+}
+
 pub fn payment_received(e: &Env, payer: &soroban_sdk::Address, ref_id: &soroban_sdk::String, amount: i128) {
     e.events().publish(
         (PAYMENT_RECEIVED, symbol_short!("payment")),
         (payer.clone(), ref_id.clone(), amount),
     );
 }
+
+pub fn payment_approved(e: &Env, payer: &soroban_sdk::Address, admin: &soroban_sdk::Address) {
+    e.events().publish(
+        (PAYMENT_APPROVED, symbol_short!("review")),
+        (payer.clone(), admin.clone()),
+    );
+}
+
+pub fn payment_rejected(
+    e: &Env,
+    payer: &soroban_sdk::Address,
+    admin: &soroban_sdk::Address,
+    reason: &soroban_sdk::String,
+) {
+    e.events().publish(
+        (PAYMENT_REJECTED, symbol_short!("review")),
+        (payer.clone(), admin.clone(), reason.clone()),
+    );
+}
+
+pub fn payment_refunded(
+    e: &Env,
+    payer: &soroban_sdk::Address,
+    admin: &soroban_sdk::Address,
+    token_address: &soroban_sdk::Address,
+    amount: i128,
+    reason: &soroban_sdk::String,
+) {
+    e.events().publish(
+        (PAYMENT_REFUNDED, symbol_short!("refund")),
+        (
+            payer.clone(),
+            admin.clone(),
+            token_address.clone(),
+            amount,
+            reason.clone(),
+        ),
+    );
+}
+
+pub fn registration_fee_updated(
+    e: &Env,
+    admin: &soroban_sdk::Address,
+    fee_amount: i128,
+    token_id: &soroban_sdk::Address,
+) {
+    e.events().publish(
+        (REGISTRATION_FEE_UPDATED, symbol_short!("update")),
+        (admin.clone(), fee_amount, token_id.clone()),
+    );
+}
+
+pub fn fee_token_updated(e: &Env, admin: &soroban_sdk::Address, token: &soroban_sdk::Address, price: Option<i128>) {
+    e.events().publish(
+        (FEE_TOKEN_UPDATED, symbol_short!("update")),
+        (admin.clone(), token.clone(), price),
+    );
+}
+
+pub fn validity_updated(e: &Env, admin: &soroban_sdk::Address, validity_period: Option<u64>, grace_period: u64) {
+    e.events().publish(
+        (VALIDITY_UPDATED, symbol_short!("update")),
+        (admin.clone(), validity_period, grace_period),
+    );
+}
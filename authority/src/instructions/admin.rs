@@ -1,57 +1,70 @@
 use crate::errors::Error;
 use crate::events;
-use crate::state::{set_authority_data, set_registration_fee, RegisteredAuthorityData};
-use soroban_sdk::{Address, Env, String};
-// Import macros we actually use
-use crate::require_owner;
+use crate::state::{
+    get_authority_data, get_authority_level, get_fee_tokens, get_protocol_contract, get_schema_rules, is_authority,
+    is_platform_attester, push_status_change, remove_platform_attester, set_authority_data, set_fee_tokens,
+    set_grace_period, set_levy_protocol_share, set_platform_attester, set_protocol_contract, set_registration_fee,
+    set_schema_rules, set_validity_period, validity_expiry, AuthorityProfile, AuthorityStatus, AuthorityStatusChange,
+    RegisteredAuthorityData, Role, SchemaRules, VerificationLevel, DEFAULT_REGISTRATION_FEE,
+};
+use soroban_sdk::{Address, BytesN, Env, String};
 
 // ══════════════════════════════════════════════════════════════════════════════
 // ► Admin utility functions
 // ══════════════════════════════════════════════════════════════════════════════
 
-/// Get the admin address, returning an error if not initialized
+/// Get the admin address, returning an error if not initialized or renounced
 pub fn get_admin(env: &Env) -> Result<Address, Error> {
-    crate::state::get_admin(env).ok_or(Error::NotInitialized)
+    crate::access_control::get_owner(env)
 }
 
 // ══════════════════════════════════════════════════════════════════════════════
 // ► Admin Functions
 // ══════════════════════════════════════════════════════════════════════════════
 
-/// Register an authority by the admin (no fee required)
+/// Register an authority by a registrar (no fee required)
 pub fn admin_register_authority(
     env: &Env,
     admin: &Address,
     auth_to_reg: &Address,
-    metadata: &String,
+    profile: &AuthorityProfile,
 ) -> Result<(), Error> {
-    // Use macro for cleaner access control
-    crate::admin_guard!(env, admin);
+    crate::require_role!(env, admin, Role::Registrar);
+    require_not_barred(env, auth_to_reg)?;
+    validate_profile(profile)?;
 
     let data = RegisteredAuthorityData {
         address: auth_to_reg.clone(),
-        metadata: metadata.clone(),
+        profile: profile.clone(),
+        profile_version: next_profile_version(env, auth_to_reg),
         registration_time: env.ledger().timestamp(),
         ref_id: String::from_str(env, "admin_registered"), // Default ref_id for admin registrations
+        status: AuthorityStatus::Active,
+        expires_at: validity_expiry(env, env.ledger().timestamp()),
+        level: get_authority_level(env, auth_to_reg),
+        verified_until: None, // no attestation bounds a registrar's registration
     };
 
     set_authority_data(env, &data);
 
     // Publish event
-    events::admin_register_authority(env, auth_to_reg, metadata);
+    events::admin_register_authority(env, auth_to_reg, profile);
 
     Ok(())
 }
 
-/// Helper method for setting registration fee
+/// Set the verification fee amount and the token it must be paid in
 pub fn admin_set_registration_fee(
     env: &Env,
     admin: &Address,
     fee_amount: &i128,
     token_id: &Address,
 ) -> Result<(), Error> {
-    // Use macro for cleaner access control
-    crate::admin_guard!(env, admin);
+    crate::require_role!(env, admin, Role::SuperAdmin, {
+        if *fee_amount < 0 {
+            return Err(Error::InvalidFeeAmount);
+        }
+    });
 
     // Store the registration fee amount
     set_registration_fee(env, fee_amount);
@@ -59,11 +72,407 @@ pub fn admin_set_registration_fee(
     // Store the token ID if different from the current one
     if token_id != &crate::state::get_token_id(env).ok_or(Error::NotInitialized)? {
         crate::state::set_token_id(env, token_id);
+
+        // The primary token is priced by the registration fee, not the token table
+        let mut tokens = get_fee_tokens(env);
+        if tokens.contains_key(token_id.clone()) {
+            tokens.remove(token_id.clone());
+            set_fee_tokens(env, &tokens);
+        }
+    }
+
+    events::registration_fee_updated(env, admin, *fee_amount, token_id);
+
+    Ok(())
+}
+
+/// Set how long new registrations and renewals stay valid, and the grace period
+/// an expired registration stays usable for. `None` makes registrations permanent.
+///
+/// Existing registrations keep their current expiry until they are renewed.
+pub fn admin_set_validity_period(
+    env: &Env,
+    admin: &Address,
+    validity_period: &Option<u64>,
+    grace_period: &u64,
+) -> Result<(), Error> {
+    crate::require_role!(env, admin, Role::SuperAdmin, {
+        if *validity_period == Some(0) {
+            return Err(Error::InvalidValidityPeriod);
+        }
+    });
+
+    set_validity_period(env, validity_period);
+    set_grace_period(env, grace_period);
+
+    events::validity_updated(env, admin, *validity_period, *grace_period);
+
+    Ok(())
+}
+
+/// Accept an additional token for verification fees at its own price (super-admin),
+/// or update the price of an accepted one.
+///
+/// The primary fee token is priced with `admin_set_registration_fee` instead.
+pub fn admin_set_fee_token(env: &Env, admin: &Address, token: &Address, price: &i128) -> Result<(), Error> {
+    crate::require_role!(env, admin, Role::SuperAdmin, {
+        if *price < 0 {
+            return Err(Error::InvalidFeeAmount);
+        }
+        if *token == get_token_id(env)? {
+            return Err(Error::InvalidFeeToken);
+        }
+    });
+
+    let mut tokens = get_fee_tokens(env);
+    tokens.set(token.clone(), *price);
+    set_fee_tokens(env, &tokens);
+
+    events::fee_token_updated(env, admin, token, Some(*price));
+
+    Ok(())
+}
+
+/// Stop accepting an additional fee token (super-admin).
+///
+/// Fees already collected in it stay withdrawable and refundable.
+pub fn admin_remove_fee_token(env: &Env, admin: &Address, token: &Address) -> Result<(), Error> {
+    crate::require_role!(env, admin, Role::SuperAdmin);
+
+    let mut tokens = get_fee_tokens(env);
+    if tokens.remove(token.clone()).is_none() {
+        return Err(Error::InvalidFeeToken);
+    }
+    set_fee_tokens(env, &tokens);
+
+    events::fee_token_updated(env, admin, token, None);
+
+    Ok(())
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Protocol Contract
+// ══════════════════════════════════════════════════════════════════════════════
+
+/// Set or rotate the attestation protocol contract allowed to call the hooks (owner only).
+///
+/// Takes effect immediately: the previous protocol can no longer call the hooks.
+pub fn admin_set_protocol_contract(env: &Env, admin: &Address, protocol: &Address) -> Result<(), Error> {
+    crate::require_owner!(env, admin);
+
+    let previous = get_protocol_contract(env);
+    set_protocol_contract(env, protocol);
+
+    events::protocol_updated(env, admin, &previous, protocol);
+
+    Ok(())
+}
+
+/// Requires the authorization of the configured protocol contract, so that the
+/// hooks cannot be called directly with fabricated attestations.
+///
+/// # Returns
+/// * `Ok(())` - If the protocol contract authorized the call
+/// * `Err(Error::ProtocolNotConfigured)` - If no protocol contract is set
+pub fn require_protocol(env: &Env) -> Result<(), Error> {
+    let protocol = get_protocol_contract(env).ok_or(Error::ProtocolNotConfigured)?;
+    protocol.require_auth();
+    Ok(())
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Platform Attesters
+// ══════════════════════════════════════════════════════════════════════════════
+
+/// Trust a platform attester to issue verification attestations (owner only).
+///
+/// # Returns
+/// * `Ok(())` - If the attester was added
+/// * `Err(Error::RoleAlreadyGranted)` - If the attester is already trusted
+pub fn admin_add_platform_attester(env: &Env, admin: &Address, attester: &Address) -> Result<(), Error> {
+    crate::require_owner!(env, admin);
+
+    if is_platform_attester(env, attester) {
+        return Err(Error::RoleAlreadyGranted);
+    }
+    set_platform_attester(env, attester);
+
+    events::attester_added(env, admin, attester);
+
+    Ok(())
+}
+
+/// Stop trusting a platform attester (owner only).
+///
+/// Authorities it already verified stay registered, but attestations it has
+/// not resolved yet can no longer register anyone.
+///
+/// # Returns
+/// * `Ok(())` - If the attester was removed
+/// * `Err(Error::RoleNotGranted)` - If the attester is not trusted
+pub fn admin_remove_platform_attester(env: &Env, admin: &Address, attester: &Address) -> Result<(), Error> {
+    crate::require_owner!(env, admin);
+
+    if !is_platform_attester(env, attester) {
+        return Err(Error::RoleNotGranted);
+    }
+    remove_platform_attester(env, attester);
+
+    events::attester_removed(env, admin, attester);
+
+    Ok(())
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Verification Levels
+// ══════════════════════════════════════════════════════════════════════════════
+
+/// Set the verification level of a registered, active authority (registrar).
+pub fn admin_set_verification_level(
+    env: &Env,
+    admin: &Address,
+    authority: &Address,
+    level: &VerificationLevel,
+) -> Result<(), Error> {
+    crate::require_role!(env, admin, Role::Registrar);
+
+    let mut data = get_authority_data(env, authority).ok_or(Error::VerifierNotFound)?;
+    if data.status != AuthorityStatus::Active {
+        return Err(Error::VerifierInactive);
+    }
+
+    data.level = *level;
+    set_authority_data(env, &data);
+
+    events::authority_level_updated(env, authority, admin, *level);
+
+    Ok(())
+}
+
+/// Set the minimum verification level an authority needs to attest with a registered schema (super-admin).
+pub fn admin_set_schema_min_level(
+    env: &Env,
+    admin: &Address,
+    schema_uid: &BytesN<32>,
+    level: &VerificationLevel,
+) -> Result<(), Error> {
+    crate::require_role!(env, admin, Role::SuperAdmin);
+
+    let mut rules = get_schema_rules(env, schema_uid).ok_or(Error::SchemaNotRegistered)?;
+    rules.min_level = *level;
+    set_schema_rules(env, schema_uid, &rules);
+
+    events::schema_level_updated(env, schema_uid, admin, *level);
+
+    Ok(())
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Authority Profiles
+// ══════════════════════════════════════════════════════════════════════════════
+
+/// Longest value, in bytes, of any string field of an `AuthorityProfile`
+pub const MAX_PROFILE_FIELD_LEN: u32 = 256;
+
+/// Replace the profile of a registered authority (the authority itself or a registrar).
+///
+/// Every update bumps the record's `profile_version`, which is returned. Revoked
+/// authorities keep the profile they had when revoked.
+pub fn update_authority_profile(
+    env: &Env,
+    caller: &Address,
+    authority: &Address,
+    profile: &AuthorityProfile,
+) -> Result<u32, Error> {
+    if caller == authority {
+        require_init(env)?;
+        caller.require_auth();
+    } else {
+        crate::require_role!(env, caller, Role::Registrar);
+    }
+    validate_profile(profile)?;
+
+    let mut data = get_authority_data(env, authority).ok_or(Error::VerifierNotFound)?;
+    if data.status == AuthorityStatus::Revoked {
+        return Err(Error::AuthorityNotActive);
+    }
+
+    data.profile = profile.clone();
+    data.profile_version += 1;
+    set_authority_data(env, &data);
+
+    events::authority_profile_updated(env, authority, caller, data.profile_version);
+
+    Ok(data.profile_version)
+}
+
+/// Profile version for a registration of `authority`: 1 for a new authority,
+/// otherwise one past the version of its current record.
+pub fn next_profile_version(env: &Env, authority: &Address) -> u32 {
+    get_authority_data(env, authority).map_or(1, |data| data.profile_version + 1)
+}
+
+/// Returns Error::InvalidAuthorityProfile if the profile has no name or an oversized field.
+pub fn validate_profile(profile: &AuthorityProfile) -> Result<(), Error> {
+    let fields = [
+        Some(&profile.name),
+        profile.website.as_ref(),
+        profile.jurisdiction.as_ref(),
+        profile.registry_id.as_ref(),
+        profile.contact.as_ref(),
+    ];
+    if profile.name.is_empty()
+        || fields
+            .into_iter()
+            .flatten()
+            .any(|field| field.len() > MAX_PROFILE_FIELD_LEN)
+    {
+        return Err(Error::InvalidAuthorityProfile);
+    }
+    Ok(())
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Schema Registration
+// ══════════════════════════════════════════════════════════════════════════════
+
+/// Register a schema, or replace the rules of a registered one (super-admin).
+pub fn admin_register_schema(
+    env: &Env,
+    admin: &Address,
+    schema_uid: &BytesN<32>,
+    rules: &SchemaRules,
+) -> Result<(), Error> {
+    crate::require_role!(env, admin, Role::SuperAdmin, {
+        validate_schema_rules(env, rules)?;
+    });
+
+    set_schema_rules(env, schema_uid, rules);
+
+    events::schema_registered(env, schema_uid, rules);
+
+    Ok(())
+}
+
+/// Set the owner's cut of every levy in basis points (super-admin).
+///
+/// The cut is credited to the platform fee ledger and withdrawn with `admin_withdraw_fees`.
+pub fn admin_set_levy_protocol_share(env: &Env, admin: &Address, share_bps: &u32) -> Result<(), Error> {
+    crate::require_role!(env, admin, Role::SuperAdmin, {
+        if *share_bps > crate::payment::MAX_BASIS_POINTS {
+            return Err(Error::InvalidProtocolShare);
+        }
+    });
+
+    set_levy_protocol_share(env, share_bps);
+
+    events::levy_share_updated(env, admin, *share_bps);
+
+    Ok(())
+}
+
+/// Levy amount and recipient must be set together, the levy must be positive
+/// and paid to an active authority, and an expiration window cannot be zero.
+fn validate_schema_rules(env: &Env, rules: &SchemaRules) -> Result<(), Error> {
+    match (&rules.levy_amount, &rules.levy_recipient) {
+        (None, None) => {},
+        (Some(amount), Some(recipient)) => {
+            if *amount <= 0 {
+                return Err(Error::InvalidSchemaRules);
+            }
+            if !is_authority(env, recipient) {
+                return Err(Error::RecipientNotAuthority);
+            }
+        },
+        _ => return Err(Error::InvalidSchemaRules),
+    }
+    if rules.max_expiration == Some(0) {
+        return Err(Error::InvalidSchemaRules);
     }
+    Ok(())
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Authority Lifecycle
+// ══════════════════════════════════════════════════════════════════════════════
 
+/// Suspend an active authority (registrar). Suspended authorities cannot attest.
+pub fn admin_suspend_authority(env: &Env, admin: &Address, authority: &Address, reason: &String) -> Result<(), Error> {
+    crate::require_role!(env, admin, Role::Registrar);
+    change_authority_status(env, authority, AuthorityStatus::Suspended, reason, admin)?;
+    events::authority_suspended(env, authority, admin, reason);
     Ok(())
 }
 
+/// Reinstate a suspended authority (registrar).
+pub fn admin_reinstate_authority(
+    env: &Env,
+    admin: &Address,
+    authority: &Address,
+    reason: &String,
+) -> Result<(), Error> {
+    crate::require_role!(env, admin, Role::Registrar);
+    change_authority_status(env, authority, AuthorityStatus::Active, reason, admin)?;
+    events::authority_reinstated(env, authority, admin, reason);
+    Ok(())
+}
+
+/// Permanently revoke an active or suspended authority (registrar).
+pub fn admin_revoke_authority(env: &Env, admin: &Address, authority: &Address, reason: &String) -> Result<(), Error> {
+    crate::require_role!(env, admin, Role::Registrar);
+    change_authority_status(env, authority, AuthorityStatus::Revoked, reason, admin)?;
+    events::authority_revoked(env, authority, admin, reason);
+    Ok(())
+}
+
+/// Moves an authority to `new_status` and appends the change to its history.
+///
+/// Allowed transitions: Active → Suspended, Suspended → Active and
+/// Active/Suspended → Revoked. Revoked is terminal.
+pub fn change_authority_status(
+    env: &Env,
+    authority: &Address,
+    new_status: AuthorityStatus,
+    reason: &String,
+    changed_by: &Address,
+) -> Result<(), Error> {
+    let mut data = get_authority_data(env, authority).ok_or(Error::AuthorityNotFound)?;
+
+    let allowed = matches!(
+        (data.status, new_status),
+        (AuthorityStatus::Active, AuthorityStatus::Suspended)
+            | (AuthorityStatus::Suspended, AuthorityStatus::Active)
+            | (
+                AuthorityStatus::Active | AuthorityStatus::Suspended,
+                AuthorityStatus::Revoked
+            )
+    );
+    if !allowed {
+        return Err(Error::InvalidStatusTransition);
+    }
+
+    data.status = new_status;
+    set_authority_data(env, &data);
+
+    let change = AuthorityStatusChange {
+        status: new_status,
+        reason: reason.clone(),
+        changed_by: changed_by.clone(),
+        timestamp: env.ledger().timestamp(),
+    };
+    push_status_change(env, authority, &change);
+
+    Ok(())
+}
+
+/// Registration must not silently lift a suspension or revocation.
+pub fn require_not_barred(env: &Env, authority: &Address) -> Result<(), Error> {
+    match get_authority_data(env, authority) {
+        Some(data) if data.status != AuthorityStatus::Active => Err(Error::AuthorityNotActive),
+        _ => Ok(()),
+    }
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // ► Helper functions
 // ══════════════════════════════════════════════════════════════════════════════
@@ -81,3 +490,17 @@ pub fn require_init(env: &Env) -> Result<(), Error> {
 pub fn get_token_id(env: &Env) -> Result<Address, Error> {
     crate::state::get_token_id(env).ok_or(Error::NotInitialized)
 }
+
+/// Gets the current verification fee, falling back to the default if never configured
+pub fn get_registration_fee(env: &Env) -> i128 {
+    crate::state::get_registration_fee(env).unwrap_or(DEFAULT_REGISTRATION_FEE)
+}
+
+/// Gets the verification fee payable in `token`: the registration fee for the
+/// primary token, the table price for an additional accepted token.
+pub fn get_fee_for_token(env: &Env, token: &Address) -> Result<i128, Error> {
+    if *token == get_token_id(env)? {
+        return Ok(get_registration_fee(env));
+    }
+    get_fee_tokens(env).get(token.clone()).ok_or(Error::InvalidFeeToken)
+}
@@ -1,58 +1,173 @@
 use crate::errors::Error;
 use crate::events;
-use crate::instructions::admin::{get_token_id, require_init};
+use crate::instructions::admin::{
+    change_authority_status, get_token_id, next_profile_version, require_init, require_not_barred, require_protocol,
+    validate_profile,
+};
+use crate::pausable::require_not_paused;
+use crate::payment;
 use crate::state::{
-    get_collected_levy, is_authority, remove_collected_levy, set_authority_data, set_collected_levy, Attestation,
-    RegisteredAuthorityData,
+    get_attestation_record, get_authority_data, get_authority_level, get_collected_levy, get_nomination,
+    get_payment_record, get_schema_min_level, get_schema_rules, has_confirmed_payment, has_lapsed,
+    is_platform_attester, is_registered, remove_collected_levy, remove_nomination, set_attestation_record,
+    set_authority_data, set_collected_levy, set_nomination, set_sponsor, Attestation, AttestationRecord,
+    AuthorityNomination, AuthorityProfile, AuthorityStatus, PaymentStatus, RegisteredAuthorityData, SchemaRules,
+    VerificationLevel, VerificationPayload, NOMINATION_PERIOD, PUBLIC_REGISTRATION_REF_ID,
 };
-use soroban_sdk::{log, token, Address, Env, String};
+use resolvers::{ResolverAttestationData, ResolverError};
+use soroban_sdk::{log, token, xdr::FromXdr, Address, Bytes, BytesN, Env, String};
 
 // ══════════════════════════════════════════════════════════════════════════════
 // ► Public Authority Registration
 // ══════════════════════════════════════════════════════════════════════════════
 
-/// Register an authority by paying the registration fee
+/// Apply for authority status by paying the registration fee
+///
+/// Nothing is registered here. Applying for yourself records a pending verification
+/// payment under `PUBLIC_REGISTRATION_REF_ID`, like `pay_verification_fee`.
+/// Applying for another address only nominates it: the fee is held in escrow until
+/// the nominee consents with `accept_authority_nomination`. Either way the applicant
+/// becomes an authority only once a registrar approves the payment and an attestation
+/// by a trusted platform attester resolves; the registered profile comes from that
+/// attestation.
 pub fn register_authority(
     env: &Env,
     caller: &Address,
     authority_to_reg: &Address,
-    metadata: &String,
+    profile: &AuthorityProfile,
 ) -> Result<(), Error> {
     require_init(env)?;
+    require_not_paused(env)?;
     caller.require_auth();
-
-    const REGISTRATION_FEE: i128 = 100_0000000; // 100 XLM in stroops
+    require_not_barred(env, authority_to_reg)?;
+    validate_profile(profile)?;
 
     let token_id = get_token_id(env)?;
-    let token_client = token::Client::new(env, &token_id);
+    if caller != authority_to_reg {
+        return nominate_authority(env, caller, authority_to_reg, profile, &token_id);
+    }
 
-    token_client.transfer(caller, &env.current_contract_address(), &REGISTRATION_FEE);
+    let amount_paid = payment::collect_fee(env, caller, &token_id)?;
+    let ref_id = String::from_str(env, PUBLIC_REGISTRATION_REF_ID);
+    payment::record_pending_payment(env, caller, &ref_id, &token_id, amount_paid);
 
-    let data = RegisteredAuthorityData {
-        address: authority_to_reg.clone(),
-        metadata: metadata.clone(),
-        registration_time: env.ledger().timestamp(),
-        ref_id: String::from_str(env, "public_registration"), // Default ref_id for public registrations
-    };
+    Ok(())
+}
 
-    set_authority_data(env, &data);
+/// Accept a sponsored nomination and apply for authority status
+///
+/// The nominee must authorize the call before the nomination expires. The escrowed
+/// fee is credited to the fee ledger, becomes the nominee's pending verification
+/// payment under `PUBLIC_REGISTRATION_REF_ID`, and the sponsor is recorded. The
+/// nominee is registered through the same review and attestation as any applicant.
+///
+/// # Returns
+/// * `Ok(())` - The nominee's application is recorded
+/// * `Err(Error::NominationNotFound)` - No nomination is pending for the nominee
+/// * `Err(Error::NominationExpired)` - The acceptance window has passed
+pub fn accept_authority_nomination(env: &Env, nominee: &Address) -> Result<(), Error> {
+    require_init(env)?;
+    require_not_paused(env)?;
+    nominee.require_auth();
+
+    let nomination = get_nomination(env, nominee).ok_or(Error::NominationNotFound)?;
+    if env.ledger().timestamp() > nomination.expires_at {
+        return Err(Error::NominationExpired);
+    }
+    require_not_barred(env, nominee)?;
 
-    events::authority_registered(env, caller, authority_to_reg, metadata);
+    remove_nomination(env, nominee);
+    payment::credit_fee(env, &nomination.token, nomination.amount_paid);
+    set_sponsor(env, nominee, &nomination.sponsor);
+    let ref_id = String::from_str(env, PUBLIC_REGISTRATION_REF_ID);
+    payment::record_pending_payment(env, nominee, &ref_id, &nomination.token, nomination.amount_paid);
 
+    events::nomination_accepted(env, &nomination.sponsor, nominee);
     Ok(())
 }
 
+/// Close a nomination that expired unaccepted and refund its sponsor
+///
+/// Anyone may call this once the acceptance window has passed; the escrowed fee
+/// always goes back to the sponsor.
+///
+/// # Returns
+/// * `Ok(i128)` - The amount refunded to the sponsor
+/// * `Err(Error::NominationNotFound)` - No nomination is pending for the nominee
+/// * `Err(Error::NominationNotExpired)` - The nominee can still accept
+pub fn expire_authority_nomination(env: &Env, nominee: &Address) -> Result<i128, Error> {
+    require_init(env)?;
+    require_not_paused(env)?;
+
+    let nomination = get_nomination(env, nominee).ok_or(Error::NominationNotFound)?;
+    if env.ledger().timestamp() <= nomination.expires_at {
+        return Err(Error::NominationNotExpired);
+    }
+
+    refund_nomination(env, &nomination);
+    Ok(nomination.amount_paid)
+}
+
+/// Escrow the fee from `sponsor` and open a nomination for `nominee`
+///
+/// An expired nomination for the same nominee is refunded to its sponsor first.
+fn nominate_authority(
+    env: &Env,
+    sponsor: &Address,
+    nominee: &Address,
+    profile: &AuthorityProfile,
+    token_id: &Address,
+) -> Result<(), Error> {
+    let now = env.ledger().timestamp();
+    if let Some(previous) = get_nomination(env, nominee) {
+        if now <= previous.expires_at {
+            return Err(Error::NominationPending);
+        }
+        refund_nomination(env, &previous);
+    }
+
+    let amount_paid = payment::escrow_fee(env, sponsor, token_id)?;
+    let nomination = AuthorityNomination {
+        sponsor: sponsor.clone(),
+        nominee: nominee.clone(),
+        profile: profile.clone(),
+        token: token_id.clone(),
+        amount_paid,
+        created_at: now,
+        expires_at: now + NOMINATION_PERIOD,
+    };
+    set_nomination(env, &nomination);
+
+    events::authority_nominated(env, &nomination);
+    Ok(())
+}
+
+/// Remove a nomination and return its escrowed fee to the sponsor
+fn refund_nomination(env: &Env, nomination: &AuthorityNomination) {
+    remove_nomination(env, &nomination.nominee);
+    token::Client::new(env, &nomination.token).transfer(
+        &env.current_contract_address(),
+        &nomination.sponsor,
+        &nomination.amount_paid,
+    );
+    events::nomination_expired(env, nomination);
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // ► Attestation & Revocation Hook Functions
 // ══════════════════════════════════════════════════════════════════════════════
+//
+// Like the resolver interface hooks below, these can only be called with the
+// authorization of the configured protocol contract.
 
 /// Attestation hook for verifying authority
 pub fn attest(env: &Env, attestation: &Attestation) -> Result<bool, Error> {
     require_init(env)?;
-    if !is_authority(env, &attestation.attester) {
-        log!(env, "Attest hook: {} is NOT an authority.", attestation.attester);
-        return Err(Error::AttesterNotAuthority);
-    }
+    require_protocol(env)?;
+    require_not_paused(env)?;
+    require_active_authority(env, &attestation.attester)?;
+    let rules = require_schema_rules(env, attestation)?;
+    payment::collect_levy(env, &attestation.attester, &attestation.schema_uid, &rules)?;
 
     log!(
         env,
@@ -66,102 +181,260 @@ pub fn attest(env: &Env, attestation: &Attestation) -> Result<bool, Error> {
 /// Revocation hook for verifying authority
 pub fn revoke(env: &Env, attestation: &Attestation) -> Result<bool, Error> {
     require_init(env)?;
-    if is_authority(env, &attestation.attester) {
+    require_protocol(env)?;
+    require_active_authority(env, &attestation.attester)?;
+    if matches!(get_schema_rules(env, &attestation.schema_uid), Some(rules) if !rules.revocable) {
+        return Err(Error::SchemaNotRevocable);
+    }
+
+    log!(
+        env,
+        "Revoke hook: Authority {} authorized for schema {:?}",
+        attestation.attester,
+        attestation.schema_uid
+    );
+    Ok(true)
+}
+
+/// Fails unless the attester is a registered, active and unexpired authority.
+fn require_active_authority(env: &Env, attester: &Address) -> Result<(), Error> {
+    let Some(data) = get_authority_data(env, attester) else {
+        log!(env, "Hook: {} is NOT an authority.", attester);
+        return Err(Error::AttesterNotAuthority);
+    };
+    if data.status != AuthorityStatus::Active {
+        log!(env, "Hook: authority {} is suspended or revoked.", attester);
+        return Err(Error::AuthorityNotActive);
+    }
+    if has_lapsed(env, data.expires_at) {
+        log!(env, "Hook: registration of authority {} has expired.", attester);
+        return Err(Error::AuthorityExpired);
+    }
+    Ok(())
+}
+
+/// Checks an attestation against the rules of its schema, which must be registered.
+fn require_schema_rules(env: &Env, attestation: &Attestation) -> Result<SchemaRules, Error> {
+    let rules = get_schema_rules(env, &attestation.schema_uid).ok_or(Error::SchemaNotRegistered)?;
+
+    if !rules.allowed_authorities.is_empty() && !rules.allowed_authorities.contains(&attestation.attester) {
+        return Err(Error::UnauthorizedVerifier);
+    }
+    require_schema_level(env, &attestation.attester, &attestation.schema_uid)?;
+    if attestation.revocable && !rules.revocable {
+        return Err(Error::SchemaNotRevocable);
+    }
+    if let Some(window) = rules.max_expiration {
+        match attestation.expiration_time {
+            Some(expiration) if expiration <= attestation.time.saturating_add(window) => {},
+            _ => return Err(Error::InvalidExpiration),
+        }
+    }
+    Ok(rules)
+}
+
+/// Fails with `ExceedsVerificationLevel` if the schema requires a higher tier than the attester holds.
+fn require_schema_level(env: &Env, attester: &Address, schema_uid: &BytesN<32>) -> Result<(), Error> {
+    if get_authority_level(env, attester) < get_schema_min_level(env, schema_uid) {
         log!(
             env,
-            "Revoke hook: Authority {} authorized for schema {:?}",
-            attestation.attester,
-            attestation.schema_uid
+            "Hook: {} is below the level required by schema {:?}",
+            attester,
+            schema_uid
         );
-        Ok(true)
-    } else {
-        log!(env, "Revoke hook: {} is NOT an authority.", attestation.attester);
-        Err(Error::AttesterNotAuthority)
+        return Err(Error::ExceedsVerificationLevel);
     }
+    Ok(())
 }
 
 // ══════════════════════════════════════════════════════════════════════════════
-// ► Levy Withdrawal
+// ► Resolver Interface Hooks
 // ══════════════════════════════════════════════════════════════════════════════
-
-/// Withdraw collected levies for a recipient
-pub fn withdraw_levies(env: &Env, caller: &Address) -> Result<(), Error> {
-    require_init(env)?;
-    caller.require_auth();
-    if !is_authority(env, caller) {
-        log!(env, "Withdrawal attempt by non-authority: {}", caller);
-        return Err(Error::NotAuthorized);
+//
+// Every hook requires the authorization of the configured protocol contract and
+// fails with `NotAuthorized` without it, or when none is configured.
+
+/// `onattest` hook: verification attestations are issued by a trusted platform
+/// attester (`NotAuthorized` otherwise) for a recipient with an approved
+/// payment (`ValidationFailed` otherwise); the recipient is the organization
+/// that becomes an authority. Suspended or revoked recipients are rejected,
+/// and the tier the attestation grants the recipient must meet the schema's
+/// minimum level. Level failures surface as `NotAuthorized` because the
+/// resolver interface has a fixed error type. On registered schemas the
+/// recipient pays the schema levy.
+///
+/// The attestation's `data` must be an XDR-encoded `VerificationPayload` whose
+/// `ref_id` matches the recipient's payment (`ValidationFailed` otherwise). It is
+/// recorded with the attestation by uid so that `onresolve`, which only receives
+/// the uid and attester, can register the authority from it afterwards.
+pub fn on_attest(env: &Env, attestation: &ResolverAttestationData) -> Result<bool, ResolverError> {
+    require_protocol(env).map_err(|_| ResolverError::NotAuthorized)?;
+    require_not_paused(env).map_err(|_| ResolverError::Paused)?;
+    if !is_platform_attester(env, &attestation.attester) {
+        return Err(ResolverError::NotAuthorized);
+    }
+    if !has_confirmed_payment(env, &attestation.recipient) {
+        return Err(ResolverError::ValidationFailed);
+    }
+    let payload = decode_payload(env, &attestation.data)?;
+    require_matching_ref_id(env, &attestation.recipient, &payload)?;
+    require_not_barred(env, &attestation.recipient).map_err(|_| ResolverError::NotAuthorized)?;
+    let level = VerificationLevel::from_u32(payload.tier).ok_or(ResolverError::InvalidAttestation)?;
+    if level < get_schema_min_level(env, &attestation.schema_uid) {
+        return Err(ResolverError::NotAuthorized);
+    }
+    if let Some(rules) = get_schema_rules(env, &attestation.schema_uid) {
+        payment::collect_levy(env, &attestation.recipient, &attestation.schema_uid, &rules)
+            .map_err(|_| ResolverError::TokenTransferFailed)?;
     }
 
-    let balance = get_collected_levy(env, caller);
+    let record = AttestationRecord {
+        uid: attestation.uid.clone(),
+        schema_uid: attestation.schema_uid.clone(),
+        attester: attestation.attester.clone(),
+        recipient: attestation.recipient.clone(),
+        payload,
+        revoked: false,
+    };
+    set_attestation_record(env, &record);
 
-    if balance <= 0 {
-        log!(env, "Withdrawal attempt by {}: No balance to withdraw.", caller);
-        return Err(Error::NothingToWithdraw);
+    Ok(true)
+}
+
+/// Decode and validate the `VerificationPayload` carried as XDR in an attestation's
+/// `data`. An empty payload, an invalid profile, an unknown tier or a validity
+/// already over is an invalid attestation; bytes that are not XDR at all abort
+/// the call in the host.
+fn decode_payload(env: &Env, data: &Bytes) -> Result<VerificationPayload, ResolverError> {
+    if data.is_empty() {
+        return Err(ResolverError::InvalidAttestation);
+    }
+    let payload = VerificationPayload::from_xdr(env, data).map_err(|_| ResolverError::InvalidAttestation)?;
+    validate_profile(&payload.profile).map_err(|_| ResolverError::InvalidAttestation)?;
+    if VerificationLevel::from_u32(payload.tier).is_none() {
+        return Err(ResolverError::InvalidAttestation);
+    }
+    if matches!(payload.valid_until, Some(valid_until) if valid_until <= env.ledger().timestamp()) {
+        return Err(ResolverError::InvalidAttestation);
     }
+    Ok(payload)
+}
 
-    log!(env, "Attempting withdrawal for {}: amount {}", caller, balance);
+/// The payload must be about the organization the payer paid for
+fn require_matching_ref_id(env: &Env, payer: &Address, payload: &VerificationPayload) -> Result<(), ResolverError> {
+    match get_payment_record(env, payer) {
+        Some(payment) if payment.ref_id == payload.ref_id => Ok(()),
+        _ => Err(ResolverError::ValidationFailed),
+    }
+}
 
-    let token_id = get_token_id(env)?;
-    let token_client = token::Client::new(env, &token_id);
+/// `onrevoke` hook: a verification attestation can only be revoked by the
+/// attester that created it. The authority status itself is withdrawn in
+/// `onresolve` once the protocol has marked the attestation revoked.
+pub fn on_revoke(env: &Env, attestation: &ResolverAttestationData) -> Result<bool, ResolverError> {
+    require_protocol(env).map_err(|_| ResolverError::NotAuthorized)?;
+    let mut record = get_attestation_record(env, &attestation.uid).ok_or(ResolverError::InvalidAttestation)?;
+    if record.attester != attestation.attester {
+        return Err(ResolverError::NotAuthorized);
+    }
 
-    // Reset balance before transfer to prevent reentrancy issues
-    set_collected_levy(env, caller, &0i128);
+    record.revoked = true;
+    set_attestation_record(env, &record);
 
-    token_client.transfer(&env.current_contract_address(), caller, &balance);
+    Ok(true)
+}
 
-    // Remove the storage entry completely to save space
-    remove_collected_levy(env, caller);
+/// `onresolve` hook: registers the attestation's recipient as an authority
+/// after a successful attestation, or revokes its authority status after a
+/// revocation. Registration also requires the attester to still be trusted.
+pub fn on_resolve(env: &Env, attestation_uid: &BytesN<32>, attester: &Address) -> Result<(), ResolverError> {
+    require_protocol(env).map_err(|_| ResolverError::NotAuthorized)?;
+    require_not_paused(env).map_err(|_| ResolverError::Paused)?;
+    let record = get_attestation_record(env, attestation_uid).ok_or(ResolverError::InvalidAttestation)?;
+    if record.attester != *attester {
+        return Err(ResolverError::NotAuthorized);
+    }
+    let authority = &record.recipient;
+
+    if record.revoked {
+        let reason = String::from_str(env, "verification attestation revoked");
+        if change_authority_status(env, authority, AuthorityStatus::Revoked, &reason, attester).is_ok() {
+            events::authority_revoked(env, authority, attester, &reason);
+        }
+        return Ok(());
+    }
 
-    // Publish withdrawal event
-    events::levy_withdrawn(env, caller, balance);
+    if !is_platform_attester(env, attester) {
+        return Err(ResolverError::NotAuthorized);
+    }
+    require_not_barred(env, authority).map_err(|_| ResolverError::NotAuthorized)?;
+    let payment = get_payment_record(env, authority)
+        .filter(|payment| payment.status == PaymentStatus::Approved)
+        .ok_or(ResolverError::ValidationFailed)?;
+    // The payer may have paid again for another organization since `onattest`
+    let payload = record.payload;
+    require_matching_ref_id(env, authority, &payload)?;
+    let level = VerificationLevel::from_u32(payload.tier).ok_or(ResolverError::InvalidAttestation)?;
+
+    let authority_data = RegisteredAuthorityData {
+        address: authority.clone(),
+        profile: payload.profile,
+        profile_version: next_profile_version(env, authority),
+        registration_time: env.ledger().timestamp(),
+        ref_id: payload.ref_id,
+        status: AuthorityStatus::Active,
+        // registration lasts as long as both the payment and the attested verification
+        expires_at: [payment.expires_at, payload.valid_until].into_iter().flatten().min(),
+        level,
+        verified_until: payload.valid_until,
+    };
+    set_authority_data(env, &authority_data);
+
+    events::authority_registered(env, attester, authority, &authority_data.profile);
 
-    log!(env, "Withdrawal successful for {}: amount {}", caller, balance);
     Ok(())
 }
 
-/// Withdraw collected XLM fees for an authority
-pub fn withdraw_fees(env: &Env, caller: &Address) -> Result<(), Error> {
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Levy Withdrawal
+// ══════════════════════════════════════════════════════════════════════════════
+
+/// Withdraw the levies a recipient collected in `token`
+///
+/// Levies are credited in the token they were charged in, so a change of the
+/// primary fee token never pays them out of another token's balance.
+pub fn withdraw_levies(env: &Env, caller: &Address, token: &Address) -> Result<(), Error> {
     require_init(env)?;
+    require_not_paused(env)?;
     caller.require_auth();
-    if !is_authority(env, caller) {
-        log!(env, "Fee withdrawal attempt by non-authority: {}", caller);
+    // Suspended or revoked authorities can still withdraw what they already earned
+    if !is_registered(env, caller) {
+        log!(env, "Withdrawal attempt by non-authority: {}", caller);
         return Err(Error::NotAuthorized);
     }
 
-    let balance = crate::state::get_collected_fees(env, caller);
+    let balance = get_collected_levy(env, caller, token);
 
     if balance <= 0 {
-        log!(env, "Fee withdrawal attempt by {}: No balance to withdraw.", caller);
+        log!(env, "Withdrawal attempt by {}: No balance to withdraw.", caller);
         return Err(Error::NothingToWithdraw);
     }
 
-    log!(
-        env,
-        "Attempting fee withdrawal for authority {}: amount {}",
-        caller,
-        balance
-    );
+    log!(env, "Attempting withdrawal for {}: amount {}", caller, balance);
 
-    let token_id = get_token_id(env)?;
-    let token_client = token::Client::new(env, &token_id);
+    let token_client = token::Client::new(env, token);
 
     // Reset balance before transfer to prevent reentrancy issues
-    set_collected_levy(env, caller, &0i128);
+    set_collected_levy(env, caller, token, &0i128);
 
     token_client.transfer(&env.current_contract_address(), caller, &balance);
 
     // Remove the storage entry completely to save space
-    remove_collected_levy(env, caller);
+    remove_collected_levy(env, caller, token);
 
-    // Publish withdrawal event (reuse levy_withdrawn event for now)
-    events::levy_withdrawn(env, caller, balance);
+    // Publish withdrawal event
+    events::levy_withdrawn(env, caller, token, balance);
 
-    log!(
-        env,
-        "Fee withdrawal successful for authority {}: amount {}",
-        caller,
-        balance
-    );
+    log!(env, "Withdrawal successful for {}: amount {}", caller, balance);
     Ok(())
 }
@@ -1,6 +1,6 @@
 #![no_std]
-use resolvers::{ResolverAttestationData, ResolverError};
-use soroban_sdk::{contract, contractimpl, token, Address, BytesN, Env, String};
+use resolvers::{ResolverAttestationData, ResolverError, ResolverInterface, ResolverMetadata, ResolverType};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Map, String, Vec};
 
 // Import modules
 mod access_control;
@@ -8,15 +8,30 @@ mod errors;
 mod events;
 mod instructions;
 mod macros;
+mod pausable;
+mod payment;
 mod state;
+mod upgrade;
 
 // Re-export types for external use
 pub use errors::Error;
 pub use events::{
-    ADMIN_REG_AUTH, AUTHORITY_REGISTERED, LEVY_COLLECTED, LEVY_WITHDRAWN, OWNERSHIP_RENOUNCED, OWNERSHIP_TRANSFERRED,
-    PAYMENT_RECEIVED, SCHEMA_REGISTERED,
+    ADMIN_REG_AUTH, ATTESTER_ADDED, ATTESTER_REMOVED, AUTHORITY_LEVEL_UPDATED, AUTHORITY_NOMINATED,
+    AUTHORITY_PROFILE_UPDATED, AUTHORITY_REGISTERED, AUTHORITY_REINSTATED, AUTHORITY_RENEWED, AUTHORITY_REVOKED,
+    AUTHORITY_SUSPENDED, CONTRACT_MIGRATED, CONTRACT_PAUSED, CONTRACT_UNPAUSED, CONTRACT_UPGRADED, FEES_WITHDRAWN,
+    FEE_TOKEN_UPDATED, LEGACY_MIGRATED, LEVY_COLLECTED, LEVY_SHARE_UPDATED, LEVY_WITHDRAWN, NOMINATION_ACCEPTED,
+    NOMINATION_EXPIRED, OWNERSHIP_RENOUNCED, OWNERSHIP_TRANSFERRED, OWNERSHIP_TRANSFER_CANCELLED,
+    OWNERSHIP_TRANSFER_STARTED, PAYMENT_APPROVED, PAYMENT_RECEIVED, PAYMENT_REFUNDED, PAYMENT_REJECTED,
+    PROTOCOL_UPDATED, REGISTRATION_FEE_UPDATED, ROLE_GRANTED, ROLE_REVOKED, SCHEMA_LEVEL_UPDATED, SCHEMA_REGISTERED,
+    VALIDITY_UPDATED,
+};
+pub use state::{
+    Attestation, AttestationRecord, AuthorityBalances, AuthorityNomination, AuthorityProfile, AuthorityStatus,
+    AuthorityStatusChange, DataKey, FeeLedger, LegacyAuthorityData, LegacyPaymentRecord, PaymentRecord, PaymentStatus,
+    RegisteredAuthorityData, RegistryIndex, Role, SchemaRules, VerificationLevel, VerificationPayload,
+    NOMINATION_PERIOD, PAYMENT_HISTORY_PAGE_SIZE, PUBLIC_REGISTRATION_REF_ID, REGISTRY_PAGE_SIZE,
+    STATUS_HISTORY_PAGE_SIZE,
 };
-pub use state::{Attestation, DataKey, PaymentRecord, RegisteredAuthorityData};
 
 #[contract]
 pub struct AuthorityResolverContract;
@@ -34,6 +49,7 @@ impl AuthorityResolverContract {
         admin: Address,
         token_contract_id: Address,
         token_wasm_hash: BytesN<32>,
+        protocol_contract: Address,
     ) -> Result<(), Error> {
         if state::is_initialized(&env) {
             return Err(Error::AlreadyInitialized);
@@ -42,7 +58,10 @@ impl AuthorityResolverContract {
         state::set_admin(&env, &admin);
         state::set_token_id(&env, &token_contract_id);
         state::set_token_wasm_hash(&env, &token_wasm_hash);
+        state::set_protocol_contract(&env, &protocol_contract);
+        state::set_registration_fee(&env, &state::DEFAULT_REGISTRATION_FEE);
         state::set_initialized(&env);
+        state::set_contract_version(&env, upgrade::CONTRACT_VERSION);
         env.storage()
             .instance()
             .extend_ttl(env.storage().max_ttl() - 100, env.storage().max_ttl());
@@ -52,25 +71,138 @@ impl AuthorityResolverContract {
     // ──────────────────────────────────────────────────────────────────────────
     //                           Admin Functions
     // ──────────────────────────────────────────────────────────────────────────
+    /// Register an authority directly, without a verification payment (registrar)
     pub fn admin_register_authority(
         env: Env,
         admin: Address,
         auth_to_reg: Address,
-        metadata: String,
+        profile: AuthorityProfile,
+    ) -> Result<(), Error> {
+        instructions::admin::admin_register_authority(&env, &admin, &auth_to_reg, &profile)
+    }
+
+    /// Suspend an authority; it cannot attest until reinstated (registrar)
+    pub fn suspend_authority(env: Env, admin: Address, authority: Address, reason: String) -> Result<(), Error> {
+        instructions::admin::admin_suspend_authority(&env, &admin, &authority, &reason)
+    }
+
+    /// Reinstate a suspended authority (registrar)
+    pub fn reinstate_authority(env: Env, admin: Address, authority: Address, reason: String) -> Result<(), Error> {
+        instructions::admin::admin_reinstate_authority(&env, &admin, &authority, &reason)
+    }
+
+    /// Permanently revoke an authority (registrar)
+    pub fn revoke_authority(env: Env, admin: Address, authority: Address, reason: String) -> Result<(), Error> {
+        instructions::admin::admin_revoke_authority(&env, &admin, &authority, &reason)
+    }
+
+    /// Set the verification fee and the token it is paid in (super-admin)
+    pub fn set_registration_fee(env: Env, admin: Address, fee_amount: i128, token_id: Address) -> Result<(), Error> {
+        instructions::admin::admin_set_registration_fee(&env, &admin, &fee_amount, &token_id)
+    }
+
+    /// Accept an additional fee token at its own price, or update its price (super-admin)
+    pub fn set_fee_token(env: Env, admin: Address, token: Address, price: i128) -> Result<(), Error> {
+        instructions::admin::admin_set_fee_token(&env, &admin, &token, &price)
+    }
+
+    /// Stop accepting an additional fee token; fees collected in it stay withdrawable (super-admin)
+    pub fn remove_fee_token(env: Env, admin: Address, token: Address) -> Result<(), Error> {
+        instructions::admin::admin_remove_fee_token(&env, &admin, &token)
+    }
+
+    /// Replace the profile of an authority and return its new version (the authority or a registrar)
+    pub fn update_authority_profile(
+        env: Env,
+        caller: Address,
+        authority: Address,
+        profile: AuthorityProfile,
+    ) -> Result<u32, Error> {
+        instructions::admin::update_authority_profile(&env, &caller, &authority, &profile)
+    }
+
+    /// Set the verification level of a registered, active authority (registrar)
+    pub fn set_verification_level(
+        env: Env,
+        admin: Address,
+        authority: Address,
+        level: VerificationLevel,
     ) -> Result<(), Error> {
-        instructions::admin::admin_register_authority(&env, &admin, &auth_to_reg, &metadata)
+        instructions::admin::admin_set_verification_level(&env, &admin, &authority, &level)
+    }
+
+    /// Register a schema with the rules attestations must satisfy, or replace its rules (super-admin)
+    pub fn admin_register_schema(
+        env: Env,
+        admin: Address,
+        schema_uid: BytesN<32>,
+        rules: SchemaRules,
+    ) -> Result<(), Error> {
+        instructions::admin::admin_register_schema(&env, &admin, &schema_uid, &rules)
+    }
+
+    /// Set the minimum verification level required to attest with a registered schema (super-admin)
+    pub fn set_schema_min_level(
+        env: Env,
+        admin: Address,
+        schema_uid: BytesN<32>,
+        level: VerificationLevel,
+    ) -> Result<(), Error> {
+        instructions::admin::admin_set_schema_min_level(&env, &admin, &schema_uid, &level)
+    }
+
+    /// Set or rotate the protocol contract allowed to call the resolver hooks (owner only)
+    pub fn set_protocol_contract(env: Env, admin: Address, protocol: Address) -> Result<(), Error> {
+        instructions::admin::admin_set_protocol_contract(&env, &admin, &protocol)
+    }
+
+    /// Trust a platform attester to issue verification attestations (owner only)
+    pub fn add_platform_attester(env: Env, admin: Address, attester: Address) -> Result<(), Error> {
+        instructions::admin::admin_add_platform_attester(&env, &admin, &attester)
+    }
+
+    /// Stop trusting a platform attester (owner only)
+    pub fn remove_platform_attester(env: Env, admin: Address, attester: Address) -> Result<(), Error> {
+        instructions::admin::admin_remove_platform_attester(&env, &admin, &attester)
+    }
+
+    /// Set the owner's cut of every schema levy in basis points (super-admin)
+    pub fn set_levy_protocol_share(env: Env, admin: Address, share_bps: u32) -> Result<(), Error> {
+        instructions::admin::admin_set_levy_protocol_share(&env, &admin, &share_bps)
+    }
+
+    /// Set the registration validity period in seconds (`None` for permanent
+    /// registrations) and the grace period before expired registrations lapse (super-admin)
+    pub fn set_validity_period(
+        env: Env,
+        admin: Address,
+        validity_period: Option<u64>,
+        grace_period: u64,
+    ) -> Result<(), Error> {
+        instructions::admin::admin_set_validity_period(&env, &admin, &validity_period, &grace_period)
     }
 
     // ──────────────────────────────────────────────────────────────────────────
     //                         Public/Hook Functions
     // ──────────────────────────────────────────────────────────────────────────
+    /// Apply for authority status for yourself, or nominate another address (sponsor)
     pub fn register_authority(
         env: Env,
         caller: Address,
         authority_to_reg: Address,
-        metadata: String,
+        profile: AuthorityProfile,
     ) -> Result<(), Error> {
-        instructions::resolver::register_authority(&env, &caller, &authority_to_reg, &metadata)
+        instructions::resolver::register_authority(&env, &caller, &authority_to_reg, &profile)
+    }
+
+    /// Accept a sponsored nomination and apply for authority status (nominee)
+    pub fn accept_authority_nomination(env: Env, nominee: Address) -> Result<(), Error> {
+        instructions::resolver::accept_authority_nomination(&env, &nominee)
+    }
+
+    /// Close a nomination that expired unaccepted and refund its sponsor; callable by anyone
+    pub fn expire_authority_nomination(env: Env, nominee: Address) -> Result<i128, Error> {
+        instructions::resolver::expire_authority_nomination(&env, &nominee)
     }
 
     pub fn is_authority(env: Env, authority: Address) -> Result<bool, Error> {
@@ -86,28 +218,155 @@ impl AuthorityResolverContract {
         instructions::resolver::revoke(&env, &attestation)
     }
 
-    pub fn withdraw_levies(env: Env, caller: Address) -> Result<(), Error> {
-        instructions::resolver::withdraw_levies(&env, &caller)
-    }
-
-    /// Withdraw collected XLM fees for an authority
-    pub fn withdraw_fees(env: Env, caller: Address) -> Result<(), Error> {
-        instructions::resolver::withdraw_fees(&env, &caller)
+    /// Withdraw the levies collected in `token`
+    pub fn withdraw_levies(env: Env, caller: Address, token: Address) -> Result<(), Error> {
+        instructions::resolver::withdraw_levies(&env, &caller, &token)
     }
 
     // ──────────────────────────────────────────────────────────────────────────
     //                             Getter Functions
     // ──────────────────────────────────────────────────────────────────────────
 
-    pub fn get_collected_levies(env: Env, authority: Address) -> Result<i128, Error> {
+    /// Get the registry entry of an authority, including its current status
+    pub fn get_authority_data(env: Env, authority: Address) -> Result<Option<RegisteredAuthorityData>, Error> {
+        instructions::admin::require_init(&env)?;
+        Ok(state::get_authority_data(&env, &authority))
+    }
+
+    /// Get the pending nomination of a nominee, including an expired one not yet refunded
+    pub fn get_authority_nomination(env: Env, nominee: Address) -> Result<Option<AuthorityNomination>, Error> {
+        instructions::admin::require_init(&env)?;
+        Ok(state::get_nomination(&env, &nominee))
+    }
+
+    /// Get the sponsor of an authority registered through a nomination
+    pub fn get_authority_sponsor(env: Env, authority: Address) -> Result<Option<Address>, Error> {
+        instructions::admin::require_init(&env)?;
+        Ok(state::get_sponsor(&env, &authority))
+    }
+
+    /// Get up to `limit` status changes of an authority with their reason and timestamp,
+    /// oldest first, starting at sequence number `start`
+    ///
+    /// At most `STATUS_HISTORY_PAGE_SIZE` changes are returned per call.
+    pub fn get_authority_status_history(
+        env: Env,
+        authority: Address,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<AuthorityStatusChange>, Error> {
+        instructions::admin::require_init(&env)?;
+        let limit = limit.min(state::STATUS_HISTORY_PAGE_SIZE);
+        Ok(state::get_status_history(&env, &authority, start, limit))
+    }
+
+    /// Get the number of status changes in an authority's history
+    pub fn get_authority_status_count(env: Env, authority: Address) -> Result<u32, Error> {
+        instructions::admin::require_init(&env)?;
+        Ok(state::get_status_change_count(&env, &authority))
+    }
+
+    /// Get up to `limit` registered, non-revoked authorities starting at position `start`
+    ///
+    /// At most `REGISTRY_PAGE_SIZE` addresses are returned per call. Order is not
+    /// stable across revocations, which move the last authority into the freed position.
+    pub fn list_authorities(env: Env, start: u32, limit: u32) -> Result<Vec<Address>, Error> {
+        instructions::admin::require_init(&env)?;
+        let limit = limit.min(state::REGISTRY_PAGE_SIZE);
+        Ok(state::registry_range(&env, &RegistryIndex::All, start, limit))
+    }
+
+    /// Get the number of registered, non-revoked authorities
+    pub fn authority_count(env: Env) -> Result<u32, Error> {
+        instructions::admin::require_init(&env)?;
+        Ok(state::registry_len(&env, &RegistryIndex::All))
+    }
+
+    /// Get up to `limit` non-revoked authorities registered for a platform `ref_id`, starting at `start`
+    pub fn get_authorities_by_ref_id(env: Env, ref_id: String, start: u32, limit: u32) -> Result<Vec<Address>, Error> {
+        instructions::admin::require_init(&env)?;
+        let limit = limit.min(state::REGISTRY_PAGE_SIZE);
+        Ok(state::registry_range(&env, &RegistryIndex::RefId(ref_id), start, limit))
+    }
+
+    /// Get the levies an authority collected in `token`
+    pub fn get_collected_levies(env: Env, authority: Address, token: Address) -> Result<i128, Error> {
+        instructions::admin::require_init(&env)?;
+        Ok(state::get_collected_levy(&env, &authority, &token))
+    }
+
+    /// Get the rules of a registered schema
+    pub fn get_schema_rules(env: Env, schema_uid: BytesN<32>) -> Result<Option<SchemaRules>, Error> {
+        instructions::admin::require_init(&env)?;
+        Ok(state::get_schema_rules(&env, &schema_uid))
+    }
+
+    /// Get the minimum verification level required to attest with a schema
+    pub fn get_schema_min_level(env: Env, schema_uid: BytesN<32>) -> Result<VerificationLevel, Error> {
         instructions::admin::require_init(&env)?;
-        Ok(state::get_collected_levy(&env, &authority))
+        Ok(state::get_schema_min_level(&env, &schema_uid))
     }
 
-    /// Get collected XLM fees for an authority
-    pub fn get_collected_fees(env: Env, authority: Address) -> Result<i128, Error> {
+    /// True if the authority's registration expires within `within` seconds and has not lapsed yet
+    pub fn authority_expiring(env: Env, authority: Address, within: u64) -> Result<bool, Error> {
         instructions::admin::require_init(&env)?;
-        Ok(state::get_collected_fees(&env, &authority))
+        Ok(state::is_expiring(&env, &authority, within))
+    }
+
+    /// Get the registration validity period in seconds, `None` if registrations are permanent
+    pub fn get_validity_period(env: Env) -> Result<Option<u64>, Error> {
+        instructions::admin::require_init(&env)?;
+        Ok(state::get_validity_period(&env))
+    }
+
+    /// Get the grace period in seconds
+    pub fn get_grace_period(env: Env) -> Result<u64, Error> {
+        instructions::admin::require_init(&env)?;
+        Ok(state::get_grace_period(&env))
+    }
+
+    /// Get the protocol contract allowed to call the resolver hooks
+    pub fn get_protocol_contract(env: Env) -> Result<Option<Address>, Error> {
+        instructions::admin::require_init(&env)?;
+        Ok(state::get_protocol_contract(&env))
+    }
+
+    /// Check whether an address may issue verification attestations
+    pub fn is_platform_attester(env: Env, attester: Address) -> bool {
+        state::is_platform_attester(&env, &attester)
+    }
+
+    /// Get the owner's cut of every schema levy in basis points
+    pub fn get_levy_protocol_share(env: Env) -> Result<u32, Error> {
+        instructions::admin::require_init(&env)?;
+        Ok(state::get_levy_protocol_share(&env))
+    }
+
+    /// Get the withdrawable levy balances of an authority by token
+    pub fn get_balances(env: Env, authority: Address) -> Result<AuthorityBalances, Error> {
+        instructions::admin::require_init(&env)?;
+        let mut levies = Map::new(&env);
+        for token in state::get_levy_tokens(&env, &authority).iter() {
+            levies.set(token.clone(), state::get_collected_levy(&env, &authority, &token));
+        }
+        Ok(AuthorityBalances { levies })
+    }
+
+    /// Get the current verification fee amount
+    pub fn get_registration_fee(env: Env) -> Result<i128, Error> {
+        instructions::admin::require_init(&env)?;
+        Ok(instructions::admin::get_registration_fee(&env))
+    }
+
+    /// Get the verification fee payable in a token; errors if the token is not accepted
+    pub fn get_fee_for_token(env: Env, token: Address) -> Result<i128, Error> {
+        instructions::admin::get_fee_for_token(&env, &token)
+    }
+
+    /// Get the additional accepted fee tokens and their prices, without the primary token
+    pub fn get_fee_tokens(env: Env) -> Result<Map<Address, i128>, Error> {
+        instructions::admin::require_init(&env)?;
+        Ok(state::get_fee_tokens(&env))
     }
 
     pub fn get_token_id(env: Env) -> Result<Address, Error> {
@@ -122,18 +381,61 @@ impl AuthorityResolverContract {
     //                        Ownership Management Functions
     // ──────────────────────────────────────────────────────────────────────────
 
-    /// Transfer ownership of the contract to a new address
+    /// Offer ownership of the contract to a new address; it takes effect once accepted
     ///
     /// # Arguments
     /// * `env` - The Soroban environment
     /// * `current_owner` - The current owner address (must be authenticated)
-    /// * `new_owner` - The address to transfer ownership to
+    /// * `new_owner` - The address that may accept ownership
+    /// * `live_until_ledger` - Last ledger sequence at which the offer can be accepted
     ///
     /// # Returns
-    /// * `Ok(())` - If ownership transfer is successful
+    /// * `Ok(())` - If the offer is recorded
     /// * `Err(Error)` - If not authorized or validation fails
-    pub fn transfer_ownership(env: Env, current_owner: Address, new_owner: Address) -> Result<(), Error> {
-        access_control::transfer_ownership(&env, &current_owner, &new_owner)
+    pub fn transfer_ownership(
+        env: Env,
+        current_owner: Address,
+        new_owner: Address,
+        live_until_ledger: u32,
+    ) -> Result<(), Error> {
+        access_control::transfer_ownership(&env, &current_owner, &new_owner, live_until_ledger)
+    }
+
+    /// Accept a pending ownership offer
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `new_owner` - The pending owner (must be authenticated)
+    ///
+    /// # Returns
+    /// * `Ok(())` - If ownership was transferred
+    /// * `Err(Error)` - If no unexpired offer exists for `new_owner`
+    pub fn accept_ownership(env: Env, new_owner: Address) -> Result<(), Error> {
+        access_control::accept_ownership(&env, &new_owner)
+    }
+
+    /// Cancel a pending ownership offer
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `current_owner` - The current owner address (must be authenticated)
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the offer was cancelled
+    /// * `Err(Error)` - If not authorized or no offer is pending
+    pub fn cancel_ownership_transfer(env: Env, current_owner: Address) -> Result<(), Error> {
+        access_control::cancel_ownership_transfer(&env, &current_owner)
+    }
+
+    /// Get the address a pending, unexpired ownership offer was made to
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The pending owner, or `None` if no offer is pending
+    pub fn get_pending_owner(env: Env) -> Option<Address> {
+        access_control::pending_owner(&env)
     }
 
     /// Renounce ownership of the contract (permanent action)
@@ -147,11 +449,35 @@ impl AuthorityResolverContract {
     /// * `Err(Error)` - If not authorized
     ///
     /// # Warning
-    /// This is irreversible! After renouncing ownership, all admin functions become inaccessible.
+    /// This is irreversible! After renouncing ownership, all admin functions fail with
+    /// `Error::OwnershipRenounced`.
     pub fn renounce_ownership(env: Env, current_owner: Address) -> Result<(), Error> {
         access_control::renounce_ownership(&env, &current_owner)
     }
 
+    /// Replace the placeholder owner stored by earlier renunciations with the explicit renounced state
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    /// * `Ok(bool)` - True if a legacy renunciation was migrated
+    /// * `Err(Error)` - If the contract is not initialized
+    pub fn migrate_renounced_ownership(env: Env) -> Result<bool, Error> {
+        access_control::migrate_renounced_ownership(&env)
+    }
+
+    /// Check whether ownership has been renounced
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    /// * `bool` - True if the contract has no owner
+    pub fn is_ownership_renounced(env: Env) -> bool {
+        access_control::is_ownership_renounced(&env)
+    }
+
     /// Get the current owner of the contract
     ///
     /// # Arguments
@@ -159,6 +485,7 @@ impl AuthorityResolverContract {
     ///
     /// # Returns
     /// * `Ok(Address)` - The current owner address
+    /// * `Err(Error::OwnershipRenounced)` - If ownership was renounced
     /// * `Err(Error)` - If no owner is set (contract not initialized)
     pub fn get_owner(env: Env) -> Result<Address, Error> {
         access_control::get_owner(&env)
@@ -177,95 +504,265 @@ impl AuthorityResolverContract {
     }
 
     // ──────────────────────────────────────────────────────────────────────────
-    //                      Payment and Resolver Functions
+    //                          Role Management Functions
     // ──────────────────────────────────────────────────────────────────────────
 
-    /// Pay verification fee to become eligible for authority registration
-    pub fn pay_verification_fee(env: Env, payer: Address, ref_id: String, token_address: Address) -> Result<(), Error> {
-        instructions::admin::require_init(&env)?;
-        payer.require_auth();
+    /// Grant a role to an account
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `caller` - The owner, or a super-admin for roles other than `SuperAdmin` (must be authenticated)
+    /// * `role` - The role to grant
+    /// * `account` - The address receiving the role
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the role was granted
+    /// * `Err(Error)` - If not authorized or the account already holds the role
+    pub fn grant_role(env: Env, caller: Address, role: Role, account: Address) -> Result<(), Error> {
+        access_control::grant_role(&env, &caller, role, &account)
+    }
 
-        const REGISTRATION_FEE: i128 = 100_0000000; // 100 XLM
+    /// Revoke a role from an account
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `caller` - The owner, or a super-admin for roles other than `SuperAdmin` (must be authenticated)
+    /// * `role` - The role to revoke
+    /// * `account` - The address losing the role
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the role was revoked
+    /// * `Err(Error)` - If not authorized or the account does not hold the role
+    pub fn revoke_role(env: Env, caller: Address, role: Role, account: Address) -> Result<(), Error> {
+        access_control::revoke_role(&env, &caller, role, &account)
+    }
 
-        let token_client = token::Client::new(&env, &token_address);
-        token_client.transfer(&payer, &env.current_contract_address(), &REGISTRATION_FEE);
+    /// Give up a role held by the caller
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `account` - The role holder (must be authenticated)
+    /// * `role` - The role to give up
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the role was removed
+    /// * `Err(Error)` - If the account does not hold the role
+    pub fn renounce_role(env: Env, account: Address, role: Role) -> Result<(), Error> {
+        access_control::renounce_role(&env, &account, role)
+    }
 
-        let payment = state::PaymentRecord {
-            recipient: payer.clone(),
-            timestamp: env.ledger().timestamp(),
-            ref_id: ref_id.clone(),
-            amount_paid: REGISTRATION_FEE,
-        };
+    /// Check if an account was granted a role
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `role` - The role to check
+    /// * `account` - The address to check
+    ///
+    /// # Returns
+    /// * `bool` - True if the role was granted explicitly; the owner holds every role implicitly
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        access_control::has_role(&env, role, &account)
+    }
 
-        state::record_payment(&env, &payment);
+    // ──────────────────────────────────────────────────────────────────────────
+    //                              Pause Functions
+    // ──────────────────────────────────────────────────────────────────────────
 
-        // Emit payment received event
-        events::payment_received(&env, &payer, &ref_id, REGISTRATION_FEE);
+    /// Pause hooks, `attest` and fund-moving functions in an emergency
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `caller` - The owner, a super-admin or a pauser (must be authenticated)
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the contract was paused
+    /// * `Err(Error)` - If not authorized or already paused
+    pub fn pause(env: Env, caller: Address) -> Result<(), Error> {
+        pausable::pause(&env, &caller)
+    }
 
-        Ok(())
+    /// Resume normal operation
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `caller` - The owner, a super-admin or a pauser (must be authenticated)
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the contract was unpaused
+    /// * `Err(Error)` - If not authorized or not paused
+    pub fn unpause(env: Env, caller: Address) -> Result<(), Error> {
+        pausable::unpause(&env, &caller)
+    }
+
+    /// Check whether the contract is paused
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    /// * `bool` - True while the contract is paused
+    pub fn is_paused(env: Env) -> bool {
+        pausable::is_paused(&env)
     }
 
-    /// Check if an address has confirmed payment
+    // ──────────────────────────────────────────────────────────────────────────
+    //                             Upgrade Functions
+    // ──────────────────────────────────────────────────────────────────────────
+
+    /// Replace the contract code with an uploaded WASM (owner only)
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `admin` - The contract owner (must be authenticated)
+    /// * `new_wasm_hash` - Hash of WASM already uploaded to the network
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the code was replaced; call `migrate` next
+    /// * `Err(Error)` - If not authorized
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        upgrade::upgrade(&env, &admin, &new_wasm_hash)
+    }
+
+    /// Run the storage migrations of the current code, once per version (owner only)
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `admin` - The contract owner (must be authenticated)
+    ///
+    /// # Returns
+    /// * `Ok(u32)` - The version the storage was migrated to
+    /// * `Err(Error)` - If not authorized or already migrated
+    pub fn migrate(env: Env, admin: Address) -> Result<u32, Error> {
+        upgrade::migrate(&env, &admin)
+    }
+
+    /// Move authorities and levy balances stored under legacy keys to the current layout (owner only)
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `admin` - The contract owner (must be authenticated)
+    /// * `addresses` - Accounts to migrate, at most 50 per call
+    ///
+    /// # Returns
+    /// * `Ok(u32)` - Number of legacy entries moved; zero when run again on the same batch
+    /// * `Err(Error)` - If not authorized or the batch is too large
+    pub fn migrate_legacy_entries(env: Env, admin: Address, addresses: Vec<Address>) -> Result<u32, Error> {
+        upgrade::migrate_legacy_entries(&env, &admin, &addresses)
+    }
+
+    /// Get the storage layout version, zero for deployments that predate versioning
+    pub fn get_version(env: Env) -> u32 {
+        state::get_contract_version(&env)
+    }
+
+    // ──────────────────────────────────────────────────────────────────────────
+    //                      Payment and Resolver Functions
+    // ──────────────────────────────────────────────────────────────────────────
+
+    /// Pay verification fee to become eligible for authority registration
+    pub fn pay_verification_fee(env: Env, payer: Address, ref_id: String, token_address: Address) -> Result<(), Error> {
+        payment::pay_verification_fee(&env, &payer, &ref_id, &token_address)
+    }
+
+    /// Pay the verification fee again to extend a time-bounded registration; returns the new expiry
+    pub fn renew_authority(env: Env, authority: Address, token_address: Address) -> Result<u64, Error> {
+        payment::renew_authority(&env, &authority, &token_address)
+    }
+
+    /// Check if an address has a confirmed payment that has not lapsed
     pub fn has_confirmed_payment(env: Env, payer: Address) -> bool {
         state::has_confirmed_payment(&env, &payer)
     }
 
+    /// Approve the pending payment of an applicant that passed due diligence (registrar)
+    pub fn approve_payment(env: Env, admin: Address, payer: Address) -> Result<(), Error> {
+        payment::approve_payment(&env, &admin, &payer)
+    }
+
+    /// Reject the pending payment of an applicant that failed due diligence (registrar)
+    pub fn reject_payment(env: Env, admin: Address, payer: Address, reason: String) -> Result<(), Error> {
+        payment::reject_payment(&env, &admin, &payer, &reason)
+    }
+
+    /// Refund the payment with sequence number `sequence` of a rejected applicant in the token it was paid in (registrar)
+    pub fn refund_payment(
+        env: Env,
+        admin: Address,
+        payer: Address,
+        sequence: u32,
+        reason: String,
+    ) -> Result<i128, Error> {
+        payment::refund_payment(&env, &admin, &payer, sequence, &reason)
+    }
+
     /// Get payment record for an address
     pub fn get_payment_record(env: Env, payer: Address) -> Option<state::PaymentRecord> {
-        state::get_payment_record(&env, &payer)
+        payment::get_payment_status(&env, &payer)
     }
 
-    /// Admin function to withdraw collected fees
+    /// Get up to `limit` payments of a payer, oldest first, starting at sequence number `start`
+    ///
+    /// At most `PAYMENT_HISTORY_PAGE_SIZE` records are returned per call.
+    pub fn get_payment_history(env: Env, payer: Address, start: u32, limit: u32) -> Vec<state::PaymentRecord> {
+        let limit = limit.min(state::PAYMENT_HISTORY_PAGE_SIZE);
+        state::get_payment_history(&env, &payer, start, limit)
+    }
+
+    /// Get the number of payments a payer has made
+    pub fn get_payment_count(env: Env, payer: Address) -> u32 {
+        state::get_payment_count(&env, &payer)
+    }
+
+    /// Get the sum of every payment a payer has made
+    pub fn get_total_paid(env: Env, payer: Address) -> i128 {
+        state::get_total_paid(&env, &payer)
+    }
+
+    /// Admin function to withdraw collected fees, capped by the unallocated fee balance (treasurer)
     pub fn admin_withdraw_fees(env: Env, admin: Address, token_address: Address, amount: i128) -> Result<(), Error> {
+        payment::admin_withdraw_fees(&env, &admin, &token_address, amount)
+    }
+
+    /// Get the platform fee ledger of the primary fee token (total collected vs. total withdrawn)
+    pub fn get_fee_ledger(env: Env) -> Result<state::FeeLedger, Error> {
+        let token_id = instructions::admin::get_token_id(&env)?;
+        Ok(state::get_fee_ledger(&env, &token_id))
+    }
+
+    /// Get the platform fee ledger of any token fees were collected in
+    pub fn get_token_fee_ledger(env: Env, token_address: Address) -> Result<state::FeeLedger, Error> {
         instructions::admin::require_init(&env)?;
-        admin.require_auth();
+        Ok(state::get_fee_ledger(&env, &token_address))
+    }
+}
 
-        let stored_admin = instructions::admin::get_admin(&env)?;
-        if admin != stored_admin {
-            return Err(Error::NotAuthorized);
-        }
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Resolver Interface Implementation
+// ══════════════════════════════════════════════════════════════════════════════
+#[contractimpl]
+impl ResolverInterface for AuthorityResolverContract {
+    /// Called before an attestation is created; requires a trusted platform attester and a recipient with an approved payment.
+    /// When the schema has a levy, the recipient pays it and must authorize this call alongside the protocol.
+    fn onattest(env: Env, attestation: ResolverAttestationData) -> Result<bool, ResolverError> {
+        instructions::resolver::on_attest(&env, &attestation)
+    }
 
-        let token_client = token::Client::new(&env, &token_address);
-        token_client.transfer(&env.current_contract_address(), &admin, &amount);
-        Ok(())
+    /// Called before a verification attestation is revoked; only its original attester may revoke it
+    fn onrevoke(env: Env, attestation: ResolverAttestationData) -> Result<bool, ResolverError> {
+        instructions::resolver::on_revoke(&env, &attestation)
     }
 
-    // ──────────────────────────────────────────────────────────────────────────
-    //                           Resolver Interface
-    // ──────────────────────────────────────────────────────────────────────────
+    /// Called after an attestation is created or revoked; registers or removes the authority
+    fn onresolve(env: Env, attestation_uid: BytesN<32>, attester: Address) -> Result<(), ResolverError> {
+        instructions::resolver::on_resolve(&env, &attestation_uid, &attester)
+    }
 
-    /// Called before an attestation is created (resolver interface)
-    pub fn onattest(env: Env, attestation: ResolverAttestationData) -> Result<bool, ResolverError> {
-        // Check if the attester has confirmed payment
-        if !state::has_confirmed_payment(&env, &attestation.attester) {
-            return Err(ResolverError::NotAuthorized);
+    fn metadata(env: Env) -> ResolverMetadata {
+        ResolverMetadata {
+            name: String::from_str(&env, "Authority Resolver"),
+            version: String::from_str(&env, "1.0.0"),
+            description: String::from_str(&env, "Payment-gated authority verification and registry"),
+            resolver_type: ResolverType::Authority,
         }
-        Ok(true)
-    }
-
-    /// Called after an attestation is created (resolver interface)
-    pub fn onresolve(env: Env, attestation: ResolverAttestationData) -> Result<(), ResolverError> {
-        // Register the attester as an authority after successful attestation
-        if state::has_confirmed_payment(&env, &attestation.attester) {
-            let payment_record = state::get_payment_record(&env, &attestation.attester);
-            if let Some(record) = payment_record {
-                let authority_data = state::RegisteredAuthorityData {
-                    address: attestation.attester.clone(),
-                    metadata: String::from_str(&env, "verified_authority"),
-                    registration_time: env.ledger().timestamp(),
-                    ref_id: record.ref_id,
-                };
-                state::set_authority_data(&env, &authority_data);
-
-                // Emit authority registered event
-                events::authority_registered(
-                    &env,
-                    &attestation.attester,
-                    &attestation.attester,
-                    &authority_data.metadata,
-                );
-            }
-        }
-        Ok(())
     }
 }
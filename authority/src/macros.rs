@@ -149,4 +149,52 @@ macro_rules! admin_guard {
     };
 }
 
+/// Macro to require a role at the beginning of a function
+///
+/// This macro combines all common validations for role-gated functions:
+/// 1. Contract initialization check
+/// 2. Role authorization (the owner and super-admins hold every role)
+/// 3. Optional custom validation
+///
+/// # Usage
+/// ```rust
+/// pub fn registrar_function(env: Env, caller: Address) -> Result<(), Error> {
+///     require_role!(env, caller, Role::Registrar);
+///     // Function logic here
+///     Ok(())
+/// }
+/// ```
+///
+/// # With custom validation
+/// ```rust
+/// pub fn admin_function(env: Env, caller: Address, amount: i128) -> Result<(), Error> {
+///     require_role!(env, caller, Role::SuperAdmin, {
+///         if amount < 0 {
+///             return Err(Error::InvalidFeeAmount);
+///         }
+///     });
+///     // Function logic here
+///     Ok(())
+/// }
+/// ```
+///
+/// # Generated Code
+/// The macro expands to:
+/// ```rust
+/// crate::instructions::admin::require_init(&env)?;
+/// crate::access_control::require_role(&env, &caller, Role::Registrar)?;
+/// ```
+#[macro_export]
+macro_rules! require_role {
+    ($env:expr, $caller:expr, $role:expr) => {
+        $crate::instructions::admin::require_init(&$env)?;
+        $crate::access_control::require_role(&$env, &$caller, $role)?;
+    };
+    ($env:expr, $caller:expr, $role:expr, $validation:block) => {
+        $crate::instructions::admin::require_init(&$env)?;
+        $crate::access_control::require_role(&$env, &$caller, $role)?;
+        $validation
+    };
+}
+
 // Re-export macros for easier use
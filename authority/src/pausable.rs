@@ -0,0 +1,75 @@
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Pausable Module
+// ══════════════════════════════════════════════════════════════════════════════
+//
+// Inspired by OpenZeppelin's Pausable pattern for Stellar/Soroban contracts
+// Reference: https://docs.openzeppelin.com/stellar-contracts/0.4.0/utils/pausable
+//
+// Emergency stop for the contract. While paused, the resolver hooks, `attest`
+// and every function that moves funds fail with `Error::ContractPaused`.
+// Getters and admin configuration keep working so the issue can be fixed.
+
+use crate::errors::Error;
+use crate::state::{is_paused as read_paused, set_paused, Role};
+use soroban_sdk::{Address, Env};
+
+/// Returns true while the contract is paused
+pub fn is_paused(env: &Env) -> bool {
+    read_paused(env)
+}
+
+/// Modifier-like function that fails while the contract is paused
+///
+/// # Returns
+/// * `Ok(())` - If the contract is not paused
+/// * `Err(Error::ContractPaused)` - If the contract is paused
+pub fn require_not_paused(env: &Env) -> Result<(), Error> {
+    if read_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+    Ok(())
+}
+
+/// Pauses the contract
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `caller` - The owner, a super-admin or a pauser (must be authenticated)
+///
+/// # Returns
+/// * `Ok(())` - If the contract was paused
+/// * `Err(Error::ContractPaused)` - If the contract is already paused
+/// * `Err(Error)` - If not authorized or contract not initialized
+pub fn pause(env: &Env, caller: &Address) -> Result<(), Error> {
+    crate::require_role!(env, caller, Role::Pauser);
+    require_not_paused(env)?;
+
+    set_paused(env, true);
+
+    crate::events::contract_paused(env, caller);
+
+    Ok(())
+}
+
+/// Unpauses the contract
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `caller` - The owner, a super-admin or a pauser (must be authenticated)
+///
+/// # Returns
+/// * `Ok(())` - If the contract was unpaused
+/// * `Err(Error::ContractNotPaused)` - If the contract is not paused
+/// * `Err(Error)` - If not authorized or contract not initialized
+pub fn unpause(env: &Env, caller: &Address) -> Result<(), Error> {
+    crate::require_role!(env, caller, Role::Pauser);
+    if !read_paused(env) {
+        return Err(Error::ContractNotPaused);
+    }
+
+    set_paused(env, false);
+
+    crate::events::contract_unpaused(env, caller);
+
+    Ok(())
+}
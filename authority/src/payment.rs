@@ -1,102 +1,371 @@
 use crate::errors::Error;
-use crate::state::{PaymentRecord, get_registration_fee, record_payment};
-use soroban_sdk::{Address, Env, String, token};
+use crate::events;
+use crate::instructions::admin::{get_fee_for_token, get_token_id, require_init};
+use crate::pausable::require_not_paused;
+use crate::state::{
+    get_authority_data, get_fee_ledger, get_levy_protocol_share, get_logged_payment, get_payment_record,
+    get_validity_period, has_lapsed, is_registered, record_payment, set_authority_data, set_fee_ledger,
+    set_payment_status, update_collected_levy, validity_expiry, AuthorityStatus, PaymentRecord, PaymentStatus, Role,
+    SchemaRules,
+};
+use soroban_sdk::{token, Address, BytesN, Env, String};
+
+/// Denominator for the levy protocol share (100% = 10_000 basis points)
+pub const MAX_BASIS_POINTS: u32 = 10_000;
 
 // ══════════════════════════════════════════════════════════════════════════════
 // ► Payment Collection and Verification
 // ══════════════════════════════════════════════════════════════════════════════
 
 /// Process payment for authority verification eligibility
-/// 
+///
 /// This is the entry point for organizations wanting to become verified authorities.
 /// They pay once and become eligible for platform-issued attestations.
 ///
 /// # Business Logic
-/// 1. Organization pays the current verification fee (default 100 XLM)
+/// 1. Organization pays the verification fee priced for the chosen token (default 100 XLM)
 /// 2. Payment is recorded in immutable ledger with platform reference ID
-/// 3. Platform can then issue delegated attestations for this organization
-/// 4. Payment eligibility is permanent (no expiration)
+/// 3. Fee is credited to the platform's fee ledger
+/// 4. The payment stays Pending until a registrar approves or rejects it after due diligence
+/// 5. Once approved, the platform can issue delegated attestations for this organization
+/// 6. Payment eligibility lasts for the configured validity period, or is
+///    permanent when no validity period is set
 ///
 /// # Security Model
 /// - **Authorization Required**: Only the payer can authorize their own payment
+/// - **Accepted Tokens Only**: Payments in tokens other than `TokenId` or the fee token table are rejected
 /// - **Atomic Operation**: Token transfer and record creation are atomic
-/// - **Immutable Record**: Payment cannot be deleted or modified after creation
 /// - **Event Auditing**: All payments are publicly verifiable through events
 ///
 /// # Parameters
 /// * `env` - Soroban environment for storage and crypto operations
 /// * `payer` - Organization's wallet address (must authorize transaction)
 /// * `ref_id` - Platform's internal reference ID for this organization
-/// * `token_address` - XLM token contract address for fee payment
+/// * `token_address` - Accepted fee token to pay in; it is recorded for refunds
 ///
 /// # Returns
 /// * `Ok(())` - Payment processed successfully
+/// * `Err(Error::InvalidFeeToken)` - Token is not an accepted fee token
 /// * `Err(Error)` - Payment failed (insufficient funds, authorization, etc.)
 ///
 /// # Attack Vectors & Mitigations
 /// * **Double Payment**: Paying multiple times to confuse system
-///   - *Mitigation*: Only latest payment record is kept; no additional benefit
-/// * **Fake Reference IDs**: Using invalid or manipulated ref_ids  
+///   - *Mitigation*: Only the latest payment record grants eligibility; earlier ones stay in the payment log
+/// * **Fake Reference IDs**: Using invalid or manipulated ref_ids
 ///   - *Mitigation*: Platform validates ref_ids off-chain before issuing attestations
 /// * **Payment Bypass**: Attempting to record payment without token transfer
 ///   - *Mitigation*: Token transfer must succeed or entire transaction reverts
-/// * **Authorization Bypass**: Paying for someone else without permission
-///   - *Mitigation*: Strict authorization requirement from payer address
+/// * **Worthless Token**: Paying with a self-minted token
+///   - *Mitigation*: Only owner-approved fee tokens are accepted
 ///
 /// # Integration Notes
 /// - Platform monitors "PAYMENT_RECEIVED" events to trigger due diligence
 /// - ref_id links on-chain payment to off-chain organization data
 /// - Payment enables attestation eligibility but doesn't guarantee attestation issuance
-pub fn pay_verification_fee(
-    env: &Env,
-    payer: &Address,
-    ref_id: &String,
-    token_address: &Address,
-) -> Result<(), Error> {
+pub fn pay_verification_fee(env: &Env, payer: &Address, ref_id: &String, token_address: &Address) -> Result<(), Error> {
+    require_init(env)?;
+    require_not_paused(env)?;
+
     // SECURITY: Require authorization from the paying address
     // This prevents unauthorized payments on behalf of others
     payer.require_auth();
-    
-    // Get current fee amount (configurable by admin)
-    let fee_amount = get_registration_fee(env).unwrap_or(100_0000000); // 100 XLM default
-    
-    // CRITICAL: Transfer tokens from payer to contract
-    // This is the actual payment - if this fails, entire transaction reverts
-    // No payment record is created without successful token transfer
-    let contract_address = env.current_contract_address();
-    token::Client::new(env, token_address).transfer(
-        payer,
-        &contract_address, 
-        &fee_amount
-    );
-    
-    // Record payment in immutable ledger
-    // This creates permanent proof of payment for resolver validation
+
+    let fee_amount = collect_fee(env, payer, token_address)?;
+    record_pending_payment(env, payer, ref_id, token_address, fee_amount);
+
+    Ok(())
+}
+
+/// Record a fee already collected for `payer` as a pending verification payment
+///
+/// Shared by every path through which an organization applies for authority
+/// status, so that each application waits for the same registrar review and
+/// platform attestation.
+pub fn record_pending_payment(env: &Env, payer: &Address, ref_id: &String, token_address: &Address, amount_paid: i128) {
+    // Record payment in the ledger
+    // This creates proof of payment for resolver validation
     let payment_record = PaymentRecord {
         recipient: payer.clone(),
-        timestamp: env.ledger().timestamp(),    // Blockchain timestamp (cannot be manipulated)
-        ref_id: ref_id.clone(),                 // Platform's organization reference
-        amount_paid: fee_amount,                // Actual amount paid (for fee change tracking)
+        timestamp: env.ledger().timestamp(), // Blockchain timestamp (cannot be manipulated)
+        ref_id: ref_id.clone(),              // Platform's organization reference
+        amount_paid,                         // Actual amount paid (for fee change tracking)
+        expires_at: validity_expiry(env, env.ledger().timestamp()),
+        sequence: 0, // assigned by record_payment
+        token: token_address.clone(),
+        status: PaymentStatus::Pending, // awaits due diligence
     };
-    
-    // Store payment record (overwrites any previous payment for same address)
+
+    // Store payment record: it replaces the latest record and is appended to the payer's log
     record_payment(env, &payment_record);
-    
+
     // Emit public event for platform monitoring and transparency
     // Platform uses this to trigger off-chain due diligence process
-    env.events().publish(
-        (String::from_str(env, "PAYMENT_RECEIVED"), payer),
-        (fee_amount, ref_id.clone()),
-    );
-    
+    events::payment_received(env, payer, ref_id, amount_paid);
+}
+
+/// Renew a time-bounded authority registration for another validity period
+///
+/// Organizations pay the current verification fee again to keep their authority
+/// status. Renewing before the registration lapses (including during the grace
+/// period) extends from the current expiry, so no paid time is lost; renewing a
+/// lapsed registration starts a new period from now. A renewal never extends past
+/// the `valid_until` of the attestation that registered the authority; once the
+/// registration reaches it, only a new platform attestation can extend it.
+///
+/// # Parameters
+/// * `env` - Soroban environment for storage and operations
+/// * `authority` - Registered authority renewing (must authorize and pays the fee)
+/// * `token_address` - Token contract for fee payment (must be an accepted fee token)
+///
+/// # Returns
+/// * `Ok(u64)` - The new expiry timestamp
+/// * `Err(Error::AuthorityNotFound)` - Address is not a registered authority
+/// * `Err(Error::AuthorityNotActive)` - Authority is suspended or revoked
+/// * `Err(Error::RenewalNotRequired)` - Registration is permanent or no validity period is configured
+/// * `Err(Error::VerificationExpired)` - The attested verification ends by the current expiry
+/// * `Err(Error::InvalidFeeToken)` - Token is not an accepted fee token
+/// * `Err(Error::ContractPaused)` - Contract is paused
+pub fn renew_authority(env: &Env, authority: &Address, token_address: &Address) -> Result<u64, Error> {
+    require_init(env)?;
+    require_not_paused(env)?;
+    authority.require_auth();
+
+    let mut data = get_authority_data(env, authority).ok_or(Error::AuthorityNotFound)?;
+    if data.status != AuthorityStatus::Active {
+        return Err(Error::AuthorityNotActive);
+    }
+    let period = get_validity_period(env).ok_or(Error::RenewalNotRequired)?;
+    let current_expiry = data.expires_at.ok_or(Error::RenewalNotRequired)?;
+
+    let now = env.ledger().timestamp();
+    let renew_from = if has_lapsed(env, Some(current_expiry)) {
+        now
+    } else {
+        current_expiry
+    };
+    let mut expires_at = renew_from.saturating_add(period);
+    // The attestation only vouches for the organization until its `valid_until`
+    if let Some(verified_until) = data.verified_until {
+        if verified_until <= renew_from {
+            return Err(Error::VerificationExpired);
+        }
+        expires_at = expires_at.min(verified_until);
+    }
+
+    let fee_amount = collect_fee(env, authority, token_address)?;
+
+    data.expires_at = Some(expires_at);
+    set_authority_data(env, &data);
+
+    // Keep the payment record in step so `onattest` eligibility follows the renewal
+    let payment_record = PaymentRecord {
+        recipient: authority.clone(),
+        timestamp: now,
+        ref_id: data.ref_id.clone(),
+        amount_paid: fee_amount,
+        expires_at: Some(expires_at),
+        sequence: 0, // assigned by record_payment
+        token: token_address.clone(),
+        status: PaymentStatus::Approved, // the authority already passed due diligence
+    };
+    record_payment(env, &payment_record);
+
+    events::authority_renewed(env, authority, expires_at, fee_amount);
+
+    Ok(expires_at)
+}
+
+/// Transfer the verification fee priced for `token_address` from `payer` and
+/// credit it to that token's fee ledger
+///
+/// Shared by every path that charges the verification fee so that the accounting
+/// ledger always matches the tokens actually received. Callers are responsible for
+/// requiring the payer's authorization.
+///
+/// # Returns
+/// * `Ok(i128)` - The fee amount that was collected
+/// * `Err(Error::InvalidFeeToken)` - Token is not an accepted fee token
+pub fn collect_fee(env: &Env, payer: &Address, token_address: &Address) -> Result<i128, Error> {
+    let fee_amount = escrow_fee(env, payer, token_address)?;
+    credit_fee(env, token_address, fee_amount);
+    Ok(fee_amount)
+}
+
+/// Transfer the verification fee priced for `token_address` from `payer` without
+/// crediting it to the fee ledger
+///
+/// Escrowed fees cannot be withdrawn by the treasury; they are either credited
+/// later with `credit_fee` or returned to the payer.
+///
+/// # Returns
+/// * `Ok(i128)` - The fee amount that was escrowed
+/// * `Err(Error::InvalidFeeToken)` - Token is not an accepted fee token
+pub fn escrow_fee(env: &Env, payer: &Address, token_address: &Address) -> Result<i128, Error> {
+    // Only accepted fee tokens have a price (configurable by admin)
+    let fee_amount = get_fee_for_token(env, token_address)?;
+
+    // CRITICAL: Transfer tokens from payer to contract
+    // If this fails, entire transaction reverts and nothing is credited
+    token::Client::new(env, token_address).transfer(payer, &env.current_contract_address(), &fee_amount);
+
+    Ok(fee_amount)
+}
+
+/// Credit a fee already held by the contract to the token's fee ledger
+pub fn credit_fee(env: &Env, token_address: &Address, amount: i128) {
+    let mut ledger = get_fee_ledger(env, token_address);
+    ledger.total_collected += amount;
+    set_fee_ledger(env, token_address, &ledger);
+}
+
+/// Charge the schema levy to `payer` and split it between the schema's levy
+/// recipient and the platform
+///
+/// `attest` charges the attesting authority; `onattest` charges the organization
+/// being verified, the attestation's recipient, rather than the platform attester.
+/// The levy is charged in the primary fee token. The levy recipient's share is
+/// credited to its collected levies in that token and withdrawn with `withdraw_levies`;
+/// the protocol cut (`LevyProtocolShare` basis points) is credited to the platform fee
+/// ledger. Schemas without a levy are a no-op. The payer must authorize the charge,
+/// so on `onattest` the organization co-signs the attestation, and a failed transfer
+/// reverts the whole attestation.
+///
+/// # Returns
+/// * `Ok(i128)` - The levy charged, zero if the schema has none
+/// * `Err(Error::NotInitialized)` - No fee token configured
+pub fn collect_levy(env: &Env, payer: &Address, schema_uid: &BytesN<32>, rules: &SchemaRules) -> Result<i128, Error> {
+    let (Some(amount), Some(recipient)) = (rules.levy_amount, &rules.levy_recipient) else {
+        return Ok(0);
+    };
+
+    payer.require_auth();
+    let token_id = get_token_id(env)?;
+    token::Client::new(env, &token_id).transfer(payer, &env.current_contract_address(), &amount);
+
+    let protocol_cut = amount * get_levy_protocol_share(env) as i128 / MAX_BASIS_POINTS as i128;
+    if protocol_cut > 0 {
+        let mut ledger = get_fee_ledger(env, &token_id);
+        ledger.total_collected += protocol_cut;
+        set_fee_ledger(env, &token_id, &ledger);
+    }
+    let recipient_share = amount - protocol_cut;
+    update_collected_levy(env, recipient, &token_id, &recipient_share);
+
+    events::levy_collected(env, payer, recipient, schema_uid, recipient_share, protocol_cut);
+
+    Ok(amount)
+}
+
+/// Approve the pending payment of an applicant that passed due diligence (registrar)
+///
+/// Only approved payments pass the `onattest` and `onresolve` hooks.
+///
+/// # Returns
+/// * `Ok(())` - The payment is approved
+/// * `Err(Error::NotAuthorized)` - Caller does not hold the registrar role
+/// * `Err(Error::PaymentNotFound)` - The payer has no recorded payment
+/// * `Err(Error::InvalidPaymentTransition)` - The latest payment is not pending
+pub fn approve_payment(env: &Env, admin: &Address, payer: &Address) -> Result<(), Error> {
+    crate::require_role!(env, admin, Role::Registrar);
+    let payment = review_pending_payment(env, payer)?;
+
+    set_payment_status(env, &payment, PaymentStatus::Approved);
+    events::payment_approved(env, payer, admin);
+
     Ok(())
 }
 
-/// Check payment status for an address
-pub fn get_payment_status(
+/// Reject the pending payment of an applicant that failed due diligence (registrar)
+///
+/// The fee stays in the contract until it is returned with `refund_payment`.
+///
+/// # Returns
+/// * `Ok(())` - The payment is rejected
+/// * `Err(Error::NotAuthorized)` - Caller does not hold the registrar role
+/// * `Err(Error::PaymentNotFound)` - The payer has no recorded payment
+/// * `Err(Error::InvalidPaymentTransition)` - The latest payment is not pending
+pub fn reject_payment(env: &Env, admin: &Address, payer: &Address, reason: &String) -> Result<(), Error> {
+    crate::require_role!(env, admin, Role::Registrar);
+    let payment = review_pending_payment(env, payer)?;
+
+    set_payment_status(env, &payment, PaymentStatus::Rejected);
+    events::payment_rejected(env, payer, admin, reason);
+
+    Ok(())
+}
+
+/// The latest payment of `payer`, if it is still awaiting review
+fn review_pending_payment(env: &Env, payer: &Address) -> Result<PaymentRecord, Error> {
+    let payment = get_payment_record(env, payer).ok_or(Error::PaymentNotFound)?;
+    if payment.status != PaymentStatus::Pending {
+        return Err(Error::InvalidPaymentTransition);
+    }
+    Ok(payment)
+}
+
+/// Refund a payment of an applicant the platform rejected (registrar)
+///
+/// Due diligence happens off-chain after `pay_verification_fee`. When it rejects
+/// the organization, a registrar returns the fee in the token it was paid in. Any
+/// entry of the payer's log can be refunded, so fees of earlier applications are
+/// not stuck once the payer pays again. The payment moves to Refunded, so it no
+/// longer passes `onattest` if it is the latest, and the amount is booked against
+/// the fee ledger so it cannot also be withdrawn. Pending and rejected payments can
+/// always be refunded; approved ones only until the payer is registered.
+///
+/// # Parameters
+/// * `env` - Soroban environment for storage and operations
+/// * `admin` - Owner, super-admin or registrar address (must authorize transaction)
+/// * `payer` - Address whose payment is refunded
+/// * `sequence` - Sequence number of the payment in the payer's log
+/// * `reason` - Why the applicant was rejected, published in the event
+///
+/// # Returns
+/// * `Ok(i128)` - The amount refunded
+/// * `Err(Error::NotAuthorized)` - Caller does not hold the registrar role
+/// * `Err(Error::PaymentNotFound)` - The payer's log has no payment with that sequence number
+/// * `Err(Error::PaymentAlreadyRefunded)` - The payment was already refunded
+/// * `Err(Error::PaymentAlreadyUsed)` - The payment is approved and the payer is a registered authority
+/// * `Err(Error::WithdrawalExceedsBalance)` - The fee was already withdrawn from the contract
+/// * `Err(Error::ContractPaused)` - Contract is paused
+pub fn refund_payment(
     env: &Env,
-    address: &Address,
-) -> Option<PaymentRecord> {
+    admin: &Address,
+    payer: &Address,
+    sequence: u32,
+    reason: &String,
+) -> Result<i128, Error> {
+    crate::require_role!(env, admin, Role::Registrar);
+    require_not_paused(env)?;
+
+    let payment = get_logged_payment(env, payer, sequence).ok_or(Error::PaymentNotFound)?;
+    if payment.status == PaymentStatus::Refunded {
+        return Err(Error::PaymentAlreadyRefunded);
+    }
+    // Registration (or renewal) already delivered what was paid for
+    if payment.status == PaymentStatus::Approved && is_registered(env, payer) {
+        return Err(Error::PaymentAlreadyUsed);
+    }
+
+    let mut ledger = get_fee_ledger(env, &payment.token);
+    if payment.amount_paid > ledger.unallocated() {
+        return Err(Error::WithdrawalExceedsBalance);
+    }
+
+    // Update the ledger and the record before the transfer
+    ledger.total_refunded += payment.amount_paid;
+    set_fee_ledger(env, &payment.token, &ledger);
+    set_payment_status(env, &payment, PaymentStatus::Refunded);
+
+    token::Client::new(env, &payment.token).transfer(&env.current_contract_address(), payer, &payment.amount_paid);
+
+    events::payment_refunded(env, payer, admin, &payment.token, payment.amount_paid, reason);
+
+    Ok(payment.amount_paid)
+}
+
+/// Check payment status for an address
+pub fn get_payment_status(env: &Env, address: &Address) -> Option<PaymentRecord> {
     crate::state::get_payment_record(env, address)
 }
 
@@ -106,73 +375,64 @@ pub fn get_payment_status(
 /// operations. This is the primary monetization mechanism for the platform.
 ///
 /// # Business Logic
-/// - Platform collects 100 XLM per verified organization
-/// - Admin can withdraw any amount up to the contract's token balance
+/// - Platform collects the verification fee per verified organization
+/// - Admin can withdraw any amount up to the unallocated fee balance of a token
+/// - Every fee token has its own ledger, so each asset is withdrawn separately
+/// - Tokens held for other purposes (e.g. authority levies) cannot be withdrawn here
 /// - Withdrawals support partial amounts (don't require draining contract)
 /// - All withdrawals are publicly auditable through events
 ///
 /// # Access Control
-/// - **Admin Only**: Strict access control - only contract admin can withdraw
+/// - **Treasurer Only**: Only the owner, a super-admin or a treasurer can withdraw
 /// - **Multi-sig Recommended**: Admin should be multi-sig for security
-/// - **No Delegation**: Admin privilege cannot be delegated to other addresses
 ///
 /// # Parameters
 /// * `env` - Soroban environment for storage and operations
-/// * `admin` - Contract admin address (must authorize transaction)
-/// * `token_address` - Token contract to withdraw from (typically XLM)
+/// * `admin` - Owner, super-admin or treasurer address (must authorize transaction)
+/// * `token_address` - Token contract to withdraw from
 /// * `amount` - Amount to withdraw in token's smallest unit (stroops for XLM)
 ///
 /// # Returns
 /// * `Ok(())` - Withdrawal completed successfully
-/// * `Err(Error::NotAuthorized)` - Caller is not contract admin
-/// * `Err(Error)` - Token transfer failed (insufficient balance, etc.)
-///
-/// # Security Considerations
-/// - **Admin Compromise Risk**: Admin compromise allows fund extraction
-/// - **Public Transparency**: All withdrawals are publicly auditable
-/// - **Partial Withdrawals**: Admin doesn't need to drain entire balance
-/// - **Token Specificity**: Must specify exact token and amount
+/// * `Err(Error::NotAuthorized)` - Caller does not hold the treasurer role
+/// * `Err(Error::InvalidFeeAmount)` - Amount is not positive
+/// * `Err(Error::WithdrawalExceedsBalance)` - Amount exceeds the unallocated fees of the token
+/// * `Err(Error::ContractPaused)` - Contract is paused
 ///
 /// # Attack Vectors & Mitigations
 /// * **Admin Rug Pull**: Admin extracting all funds and disappearing
 ///   - *Mitigation*: Platform reputation, multi-sig admin, community governance
 /// * **Unauthorized Withdrawal**: Non-admin attempting to extract funds
-///   - *Mitigation*: Strict access control validation (only_owner)
-/// * **Token Substitution**: Withdrawing wrong token or manipulated amounts
-///   - *Mitigation*: Explicit token address and amount specification
+///   - *Mitigation*: Strict access control validation (require_role!)
+/// * **Draining Authority Funds**: Withdrawing levies owed to authorities
+///   - *Mitigation*: Withdrawals are capped by the fee ledger, not the token balance
 /// * **Gradual Extraction**: Admin slowly draining funds to avoid detection
 ///   - *Mitigation*: Public event emission enables community monitoring
-///
-/// # Business Implications
-/// - Platform sustainability depends on responsible fee management
-/// - Frequent withdrawals may signal business health or admin behavior
-/// - Community can monitor withdrawal patterns for platform evaluation
-pub fn admin_withdraw_fees(
-    env: &Env,
-    admin: &Address,
-    token_address: &Address,
-    amount: i128,
-) -> Result<(), Error> {
-    // CRITICAL ACCESS CONTROL: Only contract admin can withdraw fees
+pub fn admin_withdraw_fees(env: &Env, admin: &Address, token_address: &Address, amount: i128) -> Result<(), Error> {
+    // CRITICAL ACCESS CONTROL: Only a treasurer can withdraw fees
     // This is the primary security boundary for fund protection
-    crate::access_control::only_owner(env, admin)?;
-    
-    // Transfer tokens from contract to admin
-    // Amount must not exceed contract's token balance or transfer will fail
-    let contract_address = env.current_contract_address();
-    token::Client::new(env, token_address).transfer(
-        &contract_address,
-        admin,
-        &amount
-    );
-    
+    crate::require_role!(env, admin, Role::Treasurer);
+    require_not_paused(env)?;
+
+    if amount <= 0 {
+        return Err(Error::InvalidFeeAmount);
+    }
+
+    // Tokens never collected as fees have an empty ledger and cannot be withdrawn
+    let mut ledger = get_fee_ledger(env, token_address);
+    if amount > ledger.unallocated() {
+        return Err(Error::WithdrawalExceedsBalance);
+    }
+
+    // Update the ledger before the transfer
+    ledger.total_withdrawn += amount;
+    set_fee_ledger(env, token_address, &ledger);
+
+    token::Client::new(env, token_address).transfer(&env.current_contract_address(), admin, &amount);
+
     // TRANSPARENCY: Emit public withdrawal event
     // Enables community monitoring of admin behavior and fund management
-    // Critical for platform reputation and trust maintenance
-    env.events().publish(
-        (String::from_str(env, "FEES_WITHDRAWN"), admin),
-        amount,
-    );
-    
+    events::admin_fees_withdrawn(env, admin, token_address, amount);
+
     Ok(())
-}
\ No newline at end of file
+}
@@ -1,4 +1,9 @@
-use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, String};
+use soroban_sdk::{
+    contracttype, symbol_short, Address, Bytes, BytesN, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec,
+};
+
+/// Verification fee used until the owner configures one (100 XLM in stroops)
+pub const DEFAULT_REGISTRATION_FEE: i128 = 100_0000000;
 
 // ══════════════════════════════════════════════════════════════════════════════
 // ► Contract Data Structures
@@ -22,10 +27,84 @@ pub struct Attestation {
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[contracttype]
 pub struct PaymentRecord {
-    pub recipient: Address, // wallet address that paid
-    pub timestamp: u64,     // timestamp of payment
-    pub ref_id: String,     // their org data_uid on our platform
-    pub amount_paid: i128,  // amount paid in stroops
+    pub recipient: Address,      // wallet address that paid
+    pub timestamp: u64,          // timestamp of payment
+    pub ref_id: String,          // their org data_uid on our platform
+    pub amount_paid: i128,       // amount paid in stroops
+    pub expires_at: Option<u64>, // end of the paid validity period, None if permanent
+    pub sequence: u32,           // position in the payer's payment log, assigned by `record_payment`
+    pub token: Address,          // token the fee was paid in; refunds are returned in it
+    pub status: PaymentStatus,   // review state; only Approved payments grant eligibility
+}
+
+/// Review state of a payment
+///
+/// Pending → Approved / Rejected by a registrar after due diligence, and any
+/// state but Refunded → Refunded by `refund_payment`. Refunded is terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[contracttype]
+pub enum PaymentStatus {
+    Pending,  // paid, awaiting due diligence
+    Approved, // may attest
+    Rejected, // failed due diligence, awaiting refund
+    Refunded, // fee returned to the payer
+}
+
+/// Lifecycle status of a registered authority
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[contracttype]
+pub enum AuthorityStatus {
+    Active,    // may attest
+    Suspended, // temporarily barred, can be reinstated
+    Revoked,   // permanently barred
+}
+
+/// Trust tier of an authority, ordered from least to most trusted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[contracttype]
+pub enum VerificationLevel {
+    Basic,       // paid and verified by the platform
+    KybVerified, // business identity checked (KYB)
+    Regulated,   // licensed or regulated entity
+}
+
+impl VerificationLevel {
+    /// Level for its position in the enum (0 Basic, 1 KybVerified, 2 Regulated), None if out of range
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(VerificationLevel::Basic),
+            1 => Some(VerificationLevel::KybVerified),
+            2 => Some(VerificationLevel::Regulated),
+            _ => None,
+        }
+    }
+}
+
+/// Rules an attestation must satisfy to be accepted for a schema
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub struct SchemaRules {
+    pub allowed_authorities: Vec<Address>, // empty allows any authority at `min_level`
+    pub min_level: VerificationLevel,
+    pub levy_amount: Option<i128>, // charged per attestation, set together with `levy_recipient`
+    pub levy_recipient: Option<Address>, // registered authority credited with the levy
+    pub revocable: bool,
+    pub max_expiration: Option<u64>, // seconds after issuance; when set, attestations must expire
+}
+
+/// Public profile of an authority
+///
+/// Set at registration (from the verification attestation's `data` payload when
+/// registered through `onresolve`) and replaced with `update_authority_profile`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub struct AuthorityProfile {
+    pub name: String,                       // display name, required
+    pub website: Option<String>,            // website or domain
+    pub jurisdiction: Option<String>,       // jurisdiction of incorporation, e.g. an ISO 3166 code
+    pub registry_id: Option<String>,        // identifier in the jurisdiction's company registry
+    pub contact: Option<String>,            // contact URI, e.g. mailto: or https:
+    pub documents_hash: Option<BytesN<32>>, // hash of the off-chain verification documents
 }
 
 /// Data stored for an authority that paid for verification
@@ -33,9 +112,133 @@ pub struct PaymentRecord {
 #[contracttype]
 pub struct RegisteredAuthorityData {
     pub address: Address,
-    pub metadata: String,
+    pub profile: AuthorityProfile,
+    pub profile_version: u32, // 1 at registration, bumped by every profile update
     pub registration_time: u64,
     pub ref_id: String, // reference to their org data on platform
+    pub status: AuthorityStatus,
+    pub expires_at: Option<u64>, // end of the validity period, None if permanent
+    pub level: VerificationLevel,
+    pub verified_until: Option<u64>, // end of the attested verification, caps renewals; None if open-ended
+}
+
+/// Authority data as written by older deployments, under `DataKey::Authority`
+/// or the `DataKey::RegAuthPrefix` prefix
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub struct LegacyAuthorityData {
+    pub address: Address,
+    pub metadata: String,
+    pub registration_time: u64,
+    pub ref_id: String,
+}
+
+/// Payment record as stored by the original deployment, before validity periods,
+/// the payment log and review states
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub struct LegacyPaymentRecord {
+    pub recipient: Address,
+    pub timestamp: u64,
+    pub ref_id: String,
+    pub amount_paid: i128,
+}
+
+/// A single status change of an authority, kept for compliance audits
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub struct AuthorityStatusChange {
+    pub status: AuthorityStatus, // status after the change
+    pub reason: String,
+    pub changed_by: Address,
+    pub timestamp: u64,
+}
+
+/// Accounting ledger for verification fees owned by the platform
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub struct FeeLedger {
+    pub total_collected: i128, // all verification fees received
+    pub total_withdrawn: i128, // all fees withdrawn by the admin
+    pub total_refunded: i128,  // all fees returned to payers by `refund_payment`
+}
+
+impl FeeLedger {
+    /// Fees received but not yet withdrawn or refunded
+    pub fn unallocated(&self) -> i128 {
+        self.total_collected - self.total_withdrawn - self.total_refunded
+    }
+}
+
+/// Withdrawable balances of an authority
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub struct AuthorityBalances {
+    pub levies: Map<Address, i128>, // collected levies by token, withdrawn with `withdraw_levies`
+}
+
+/// A list in the authority registry index
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub enum RegistryIndex {
+    All,           // every authority that is not revoked
+    RefId(String), // authorities registered for one platform ref_id
+}
+
+/// Registration paid by a sponsor, awaiting the nominee's consent
+///
+/// The fee is held in escrow: it is credited to the fee ledger when the nominee
+/// accepts, and returned to the sponsor if the nomination expires unaccepted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub struct AuthorityNomination {
+    pub sponsor: Address,          // paid the fee and nominated the authority
+    pub nominee: Address,          // address to register once it accepts
+    pub profile: AuthorityProfile, // profile the authority is registered with
+    pub token: Address,            // token the fee was paid in
+    pub amount_paid: i128,         // escrowed fee in stroops
+    pub created_at: u64,           // timestamp of the nomination
+    pub expires_at: u64,           // last timestamp at which the nominee can accept
+}
+
+/// Ownership offered by the current owner, awaiting acceptance by `new_owner`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub struct PendingOwnership {
+    pub new_owner: Address,
+    pub live_until_ledger: u32, // last ledger sequence at which the offer can be accepted
+}
+
+/// Operational roles the owner can delegate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[contracttype]
+pub enum Role {
+    SuperAdmin, // every role below, and may grant or revoke them
+    Registrar,  // registers, suspends, reinstates and revokes authorities
+    Treasurer,  // withdraws platform fees
+    Pauser,     // pauses and unpauses the contract
+}
+
+/// Payload of a verification attestation, carried XDR-encoded in its `data`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub struct VerificationPayload {
+    pub profile: AuthorityProfile, // organization name and public details
+    pub ref_id: String,            // must match the `ref_id` of the payer's payment
+    pub tier: u32,                 // verification level granted, see `VerificationLevel::from_u32`
+    pub valid_until: Option<u64>,  // end of the verification, None to follow the payment
+}
+
+/// Attestation seen by `onattest`, kept so the uid-only `onresolve` hook can act on it
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub struct AttestationRecord {
+    pub uid: BytesN<32>,
+    pub schema_uid: BytesN<32>,
+    pub attester: Address,
+    pub recipient: Address,
+    pub payload: VerificationPayload, // decoded from the attestation's `data`
+    pub revoked: bool,                // set by `onrevoke`, consumed by `onresolve`
 }
 
 #[contracttype]
@@ -44,14 +247,38 @@ pub enum DataKey {
     Admin,
     Initialized,
     RegistrationFee,
-    PaymentRecord,   // Payment ledger entries
-    Authority,       // Registered authorities (post-payment)
-    TokenId,         // Token contract ID
-    TokenWasmHash,   // Token WASM hash
-    CollectedLevies, // Collected levies per authority
-    CollectedFees,   // Collected fees per authority
-    RegAuthPrefix,   // Legacy prefix for registered authorities
-    CollLevyPrefix,  // Prefix for collected levies
+    PaymentRecord,     // Payment ledger entries
+    Authority,         // Registered authorities (post-payment)
+    TokenId,           // Token contract ID
+    TokenWasmHash,     // Token WASM hash
+    CollectedLevies,   // Collected levies, keyed by (authority, token); by authority alone before migration
+    RegAuthPrefix,     // Legacy prefix for registered authorities, moved by `migrate_legacy_entries`
+    CollLevyPrefix,    // Legacy prefix for collected levies, moved by `migrate_legacy_entries`
+    AttestationRecord, // Attestations validated by onattest, keyed by uid
+    FeeLedger,         // Platform fee accounting (collected vs. withdrawn), keyed by token
+    StatusHistory,     // Authority status changes, keyed by (authority, sequence)
+    StatusCount,       // Number of status changes in an authority's history
+    ValidityPeriod,    // Registration validity in seconds, unset for permanent registrations
+    GracePeriod,       // Seconds an expired registration stays usable before it lapses
+    SchemaRules,       // Registered schemas and their rules, keyed by schema uid
+    LevyProtocolShare, // Owner's cut of every levy, in basis points
+    PendingOwner,      // Two-step ownership transfer awaiting acceptance
+    Renounced,         // Set once ownership is renounced; Admin is removed
+    RoleMember,        // Role membership, keyed by (role, account)
+    Paused,            // Emergency stop flag
+    ContractVersion,   // Storage layout version, set by `initialize` and `migrate`
+    RegistryLen,       // Number of addresses in a registry index list, keyed by RegistryIndex
+    RegistryPage,      // One page of a registry index list, keyed by (RegistryIndex, page)
+    RegistryPos,       // Position of an address in a registry index list, keyed by (RegistryIndex, address)
+    PaymentLog,        // Every payment of a payer, keyed by (payer, sequence)
+    PaymentCount,      // Number of payments in a payer's log
+    PaymentTotal,      // Sum of every payment of a payer
+    FeeTokens,         // Additional accepted fee tokens and their prices; `TokenId` is priced by `RegistrationFee`
+    Nomination,        // Sponsored registration awaiting consent, keyed by nominee
+    Sponsor,           // Sponsor of an authority registered through a nomination, keyed by authority
+    ProtocolContract,  // Attestation protocol contract allowed to call the resolver hooks
+    PlatformAttester,  // Platform attester trusted to issue verification attestations, keyed by address
+    LevyTokens,        // Tokens an authority holds collected levies in, keyed by authority
 }
 
 // ══════════════════════════════════════════════════════════════════════════════
@@ -68,52 +295,404 @@ pub fn set_admin(env: &Env, admin: &Address) {
     env.storage().instance().set(&DataKey::Admin, admin);
 }
 
+/// Reads the owner's cut of every levy in basis points, zero if never configured.
+pub fn get_levy_protocol_share(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::LevyProtocolShare).unwrap_or(0)
+}
+
+/// Writes the owner's cut of every levy in basis points.
+pub fn set_levy_protocol_share(env: &Env, share_bps: &u32) {
+    env.storage().instance().set(&DataKey::LevyProtocolShare, share_bps);
+}
+
+/// Reads the pending ownership transfer, if any (including expired offers).
+pub fn get_pending_owner(env: &Env) -> Option<PendingOwnership> {
+    env.storage().instance().get(&DataKey::PendingOwner)
+}
+
+/// Writes the pending ownership transfer.
+pub fn set_pending_owner(env: &Env, pending: &PendingOwnership) {
+    env.storage().instance().set(&DataKey::PendingOwner, pending);
+}
+
+/// Removes the pending ownership transfer.
+pub fn remove_pending_owner(env: &Env) {
+    env.storage().instance().remove(&DataKey::PendingOwner);
+}
+
+/// Removes the admin address from storage.
+pub fn remove_admin(env: &Env) {
+    env.storage().instance().remove(&DataKey::Admin);
+}
+
+/// Sets the ownership renounced flag.
+pub fn set_renounced(env: &Env) {
+    env.storage().instance().set(&DataKey::Renounced, &true);
+}
+
+/// True once ownership has been renounced.
+pub fn is_renounced(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Renounced)
+}
+
+/// Reads the storage layout version, zero for deployments that predate versioning.
+pub fn get_contract_version(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::ContractVersion).unwrap_or(0)
+}
+
+/// Writes the storage layout version.
+pub fn set_contract_version(env: &Env, version: u32) {
+    env.storage().instance().set(&DataKey::ContractVersion, &version);
+}
+
+/// True while the contract is paused.
+pub fn is_paused(env: &Env) -> bool {
+    env.storage().instance().get(&DataKey::Paused).unwrap_or(false)
+}
+
+/// Sets or clears the paused flag.
+pub fn set_paused(env: &Env, paused: bool) {
+    env.storage().instance().set(&DataKey::Paused, &paused);
+}
+
+/// True if `account` holds `role` explicitly.
+pub fn is_role_member(env: &Env, role: Role, account: &Address) -> bool {
+    let key = (DataKey::RoleMember, role, account.clone());
+    env.storage().persistent().has(&key)
+}
+
+/// Records that `account` holds `role` with appropriate TTL.
+pub fn set_role_member(env: &Env, role: Role, account: &Address) {
+    let key = (DataKey::RoleMember, role, account.clone());
+    env.storage().persistent().set(&key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, env.storage().max_ttl() - 100, env.storage().max_ttl());
+}
+
+/// Removes `role` from `account`.
+pub fn remove_role_member(env: &Env, role: Role, account: &Address) {
+    let key = (DataKey::RoleMember, role, account.clone());
+    env.storage().persistent().remove(&key);
+}
+
 /// Writes the registration fee to storage.
 pub fn set_registration_fee(env: &Env, fee: &i128) {
     env.storage().instance().set(&DataKey::RegistrationFee, fee);
 }
 
+/// Reads the registration validity period in seconds, None if registrations are permanent.
+pub fn get_validity_period(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&DataKey::ValidityPeriod)
+}
+
+/// Writes the registration validity period; None makes new registrations permanent.
+pub fn set_validity_period(env: &Env, period: &Option<u64>) {
+    match period {
+        Some(period) => env.storage().instance().set(&DataKey::ValidityPeriod, period),
+        None => env.storage().instance().remove(&DataKey::ValidityPeriod),
+    }
+}
+
+/// Reads the grace period in seconds, zero if never configured.
+pub fn get_grace_period(env: &Env) -> u64 {
+    env.storage().instance().get(&DataKey::GracePeriod).unwrap_or(0)
+}
+
+/// Writes the grace period.
+pub fn set_grace_period(env: &Env, period: &u64) {
+    env.storage().instance().set(&DataKey::GracePeriod, period);
+}
+
+/// Expiry for a registration or payment starting at `from`, None if registrations are permanent.
+pub fn validity_expiry(env: &Env, from: u64) -> Option<u64> {
+    get_validity_period(env).map(|period| from.saturating_add(period))
+}
+
+/// True once an expiry plus the grace period has passed.
+pub fn has_lapsed(env: &Env, expires_at: Option<u64>) -> bool {
+    match expires_at {
+        Some(expires_at) => env.ledger().timestamp() >= expires_at.saturating_add(get_grace_period(env)),
+        None => false,
+    }
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // ► Payment Ledger Functions
 // ══════════════════════════════════════════════════════════════════════════════
 
+/// Most payment log entries returned per history query
+pub const PAYMENT_HISTORY_PAGE_SIZE: u32 = 50;
+
 /// **CRITICAL BUSINESS FUNCTION**: Records payment in immutable ledger for access control
-pub fn record_payment(env: &Env, payment: &PaymentRecord) {
-    let key = (DataKey::PaymentRecord, payment.recipient.clone());
-    env.storage().persistent().set(&key, payment);
-    env.storage()
-        .persistent()
-        .extend_ttl(&key, env.storage().max_ttl() - 100, env.storage().max_ttl());
+///
+/// The payment becomes the payer's latest record, used for eligibility, and is
+/// appended to the payer's log under the next sequence number. Returns that number.
+pub fn record_payment(env: &Env, payment: &PaymentRecord) -> u32 {
+    let payer = &payment.recipient;
+    let sequence = get_payment_count(env, payer);
+    let payment = PaymentRecord {
+        sequence,
+        ..payment.clone()
+    };
+
+    set_persistent(env, &(DataKey::PaymentRecord, payer.clone()), &payment);
+    set_persistent(env, &(DataKey::PaymentLog, payer.clone(), sequence), &payment);
+    set_persistent(env, &(DataKey::PaymentCount, payer.clone()), &(sequence + 1));
+    let total = get_total_paid(env, payer) + payment.amount_paid;
+    set_persistent(env, &(DataKey::PaymentTotal, payer.clone()), &total);
+
+    sequence
 }
 
-/// Gets a payment record for an address
+/// Moves a recorded payment to `status` in the payer's log, and as the latest
+/// record if it is the payer's last payment. A refund is also deducted from the
+/// payer's total paid.
+pub fn set_payment_status(env: &Env, payment: &PaymentRecord, status: PaymentStatus) {
+    let payer = &payment.recipient;
+    let updated = PaymentRecord {
+        status,
+        ..payment.clone()
+    };
+
+    if updated.sequence + 1 == get_payment_count(env, payer) {
+        set_persistent(env, &(DataKey::PaymentRecord, payer.clone()), &updated);
+    }
+    set_persistent(env, &(DataKey::PaymentLog, payer.clone(), updated.sequence), &updated);
+    if status == PaymentStatus::Refunded {
+        let total = get_total_paid(env, payer) - updated.amount_paid;
+        set_persistent(env, &(DataKey::PaymentTotal, payer.clone()), &total);
+    }
+}
+
+/// Gets the latest payment record for an address
+///
+/// A record the original deployment wrote is read as an approved payment in the
+/// primary fee token until `migrate_legacy_entries` moves it into the payment log.
 pub fn get_payment_record(env: &Env, payer: &Address) -> Option<PaymentRecord> {
     let key = (DataKey::PaymentRecord, payer.clone());
+    let raw: Map<Symbol, Val> = env.storage().persistent().get(&key)?;
+    // Records in the current layout carry their position in the payment log
+    if raw.contains_key(symbol_short!("sequence")) {
+        return PaymentRecord::try_from_val(env, raw.as_val()).ok();
+    }
+    let legacy = LegacyPaymentRecord::try_from_val(env, raw.as_val()).ok()?;
+    Some(payment_from_legacy(legacy, get_token_id(env)?))
+}
+
+/// Current payment record for one written by the original deployment. Those
+/// granted eligibility on payment, in the only fee token, and did not expire.
+///
+/// The sequence is 0; `record_payment` assigns the real one when the record is migrated.
+pub fn payment_from_legacy(legacy: LegacyPaymentRecord, token: Address) -> PaymentRecord {
+    PaymentRecord {
+        recipient: legacy.recipient,
+        timestamp: legacy.timestamp,
+        ref_id: legacy.ref_id,
+        amount_paid: legacy.amount_paid,
+        expires_at: None,
+        sequence: 0,
+        token,
+        status: PaymentStatus::Approved,
+    }
+}
+
+/// Number of payments in a payer's log; also the sequence number of the next one.
+pub fn get_payment_count(env: &Env, payer: &Address) -> u32 {
+    let key = (DataKey::PaymentCount, payer.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Sum of every payment a payer has made, net of refunds.
+pub fn get_total_paid(env: &Env, payer: &Address) -> i128 {
+    let key = (DataKey::PaymentTotal, payer.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Up to `limit` payments of a payer, oldest first, starting at sequence number `start`.
+pub fn get_payment_history(env: &Env, payer: &Address, start: u32, limit: u32) -> Vec<PaymentRecord> {
+    let mut history = Vec::new(env);
+    let end = start.saturating_add(limit).min(get_payment_count(env, payer));
+    for sequence in start..end {
+        if let Some(payment) = get_logged_payment(env, payer, sequence) {
+            history.push_back(payment);
+        }
+    }
+    history
+}
+
+/// Reads the payment with sequence number `sequence` from a payer's log
+pub fn get_logged_payment(env: &Env, payer: &Address, sequence: u32) -> Option<PaymentRecord> {
+    let key = (DataKey::PaymentLog, payer.clone(), sequence);
     env.storage().persistent().get(&key)
 }
 
+/// Reads the platform fee ledger of a token, empty if no fee was ever collected in it
+pub fn get_fee_ledger(env: &Env, token: &Address) -> FeeLedger {
+    let key = (DataKey::FeeLedger, token.clone());
+    env.storage().instance().get(&key).unwrap_or(FeeLedger {
+        total_collected: 0,
+        total_withdrawn: 0,
+        total_refunded: 0,
+    })
+}
+
+/// Writes the platform fee ledger of a token.
+pub fn set_fee_ledger(env: &Env, token: &Address, ledger: &FeeLedger) {
+    let key = (DataKey::FeeLedger, token.clone());
+    env.storage().instance().set(&key, ledger);
+}
+
+/// Additional accepted fee tokens and their prices, without the primary `TokenId`.
+pub fn get_fee_tokens(env: &Env) -> Map<Address, i128> {
+    env.storage()
+        .instance()
+        .get(&DataKey::FeeTokens)
+        .unwrap_or(Map::new(env))
+}
+
+/// Writes the additional accepted fee tokens.
+pub fn set_fee_tokens(env: &Env, tokens: &Map<Address, i128>) {
+    env.storage().instance().set(&DataKey::FeeTokens, tokens);
+}
+
 /// Gets the registration fee from storage
 pub fn get_registration_fee(env: &Env) -> Option<i128> {
     env.storage().instance().get(&DataKey::RegistrationFee)
 }
 
 /// **CRITICAL ACCESS CONTROL FUNCTION**: Validates payment eligibility for attestations
+///
+/// Only the latest payment counts, and only once it is approved and until it lapses.
 pub fn has_confirmed_payment(env: &Env, payer: &Address) -> bool {
-    get_payment_record(env, payer).is_some()
+    matches!(
+        get_payment_record(env, payer),
+        Some(record) if record.status == PaymentStatus::Approved && !has_lapsed(env, record.expires_at)
+    )
 }
 
 /// Reads authority data from storage using a composite key.
+///
+/// A registration the original deployment wrote under the same key is read in the
+/// current layout until `migrate_legacy_entries` rewrites it.
 pub fn get_authority_data(env: &Env, authority: &Address) -> Option<RegisteredAuthorityData> {
     let key = (DataKey::Authority, authority.clone());
-    env.storage().persistent().get(&key)
+    let raw: Map<Symbol, Val> = env.storage().persistent().get(&key)?;
+    // Records in the current layout carry a typed profile
+    if raw.contains_key(symbol_short!("profile")) {
+        return RegisteredAuthorityData::try_from_val(env, raw.as_val()).ok();
+    }
+    let legacy = LegacyAuthorityData::try_from_val(env, raw.as_val()).ok()?;
+    Some(authority_from_legacy(authority, legacy))
+}
+
+/// Current registration for one written by an older deployment. Those only had
+/// free-form metadata, which becomes the profile name, and were permanent.
+pub fn authority_from_legacy(address: &Address, legacy: LegacyAuthorityData) -> RegisteredAuthorityData {
+    RegisteredAuthorityData {
+        address: address.clone(),
+        profile: AuthorityProfile {
+            name: legacy.metadata,
+            website: None,
+            jurisdiction: None,
+            registry_id: None,
+            contact: None,
+            documents_hash: None,
+        },
+        profile_version: 1,
+        registration_time: legacy.registration_time,
+        ref_id: legacy.ref_id,
+        status: AuthorityStatus::Active,
+        expires_at: None,
+        level: VerificationLevel::Basic,
+        verified_until: None,
+    }
 }
-/// Writes authority data to storage with appropriate TTL using a composite key.
+/// Writes authority data to storage with appropriate TTL using a composite key,
+/// keeping the registry index in step with its status and ref_id.
 pub fn set_authority_data(env: &Env, data: &RegisteredAuthorityData) {
+    let previous = get_authority_data(env, &data.address);
     let key = (DataKey::Authority, data.address.clone());
     env.storage().persistent().set(&key, data);
     env.storage()
         .persistent()
         .extend_ttl(&key, env.storage().max_ttl() - 100, env.storage().max_ttl());
+    update_registry_index(env, previous.as_ref(), data);
+}
+
+/// Verification level of an authority, Basic if it is not registered.
+///
+/// Used when (re-)registering so that an existing tier is never silently downgraded.
+pub fn get_authority_level(env: &Env, authority: &Address) -> VerificationLevel {
+    get_authority_data(env, authority)
+        .map(|data| data.level)
+        .unwrap_or(VerificationLevel::Basic)
+}
+
+/// Minimum verification level required to attest with a schema, Basic if it is not registered.
+pub fn get_schema_min_level(env: &Env, schema_uid: &BytesN<32>) -> VerificationLevel {
+    get_schema_rules(env, schema_uid)
+        .map(|rules| rules.min_level)
+        .unwrap_or(VerificationLevel::Basic)
+}
+
+/// Reads the rules of a registered schema.
+pub fn get_schema_rules(env: &Env, schema_uid: &BytesN<32>) -> Option<SchemaRules> {
+    let key = (DataKey::SchemaRules, schema_uid.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Writes the rules of a schema with appropriate TTL.
+pub fn set_schema_rules(env: &Env, schema_uid: &BytesN<32>, rules: &SchemaRules) {
+    let key = (DataKey::SchemaRules, schema_uid.clone());
+    env.storage().persistent().set(&key, rules);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, env.storage().max_ttl() - 100, env.storage().max_ttl());
+}
+
+/// Most status changes returned per history query
+pub const STATUS_HISTORY_PAGE_SIZE: u32 = 50;
+
+/// Number of status changes in an authority's history; also the sequence number of the next one.
+pub fn get_status_change_count(env: &Env, authority: &Address) -> u32 {
+    let key = (DataKey::StatusCount, authority.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Up to `limit` status changes of an authority, oldest first, starting at sequence number `start`.
+pub fn get_status_history(env: &Env, authority: &Address, start: u32, limit: u32) -> Vec<AuthorityStatusChange> {
+    let mut history = Vec::new(env);
+    let end = start.saturating_add(limit).min(get_status_change_count(env, authority));
+    for sequence in start..end {
+        let key = (DataKey::StatusHistory, authority.clone(), sequence);
+        if let Some(change) = env.storage().persistent().get(&key) {
+            history.push_back(change);
+        }
+    }
+    history
+}
+
+/// Appends a status change to the history of an authority under the next sequence number.
+pub fn push_status_change(env: &Env, authority: &Address, change: &AuthorityStatusChange) {
+    let sequence = get_status_change_count(env, authority);
+    set_persistent(env, &(DataKey::StatusHistory, authority.clone(), sequence), change);
+    set_persistent(env, &(DataKey::StatusCount, authority.clone()), &(sequence + 1));
+}
+
+/// Reads an attestation recorded by the resolver hooks.
+pub fn get_attestation_record(env: &Env, uid: &BytesN<32>) -> Option<AttestationRecord> {
+    let key = (DataKey::AttestationRecord, uid.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Writes an attestation record with appropriate TTL.
+pub fn set_attestation_record(env: &Env, record: &AttestationRecord) {
+    let key = (DataKey::AttestationRecord, record.uid.clone());
+    env.storage().persistent().set(&key, record);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, env.storage().max_ttl() - 100, env.storage().max_ttl());
 }
 
 /// Sets the initialized flag.
@@ -125,48 +704,143 @@ pub fn is_initialized(env: &Env) -> bool {
     env.storage().instance().has(&DataKey::Initialized)
 }
 
-pub fn is_authority(env: &Env, authority: &Address) -> bool {
+/// True if the address has an authority record, whatever its status.
+pub fn is_registered(env: &Env, authority: &Address) -> bool {
     let key = (DataKey::Authority, authority.clone());
     env.storage().persistent().has(&key)
 }
 
-/// Get collected levy amount for an authority
-pub fn get_collected_levy(env: &Env, authority: &Address) -> i128 {
-    let key = (DataKey::CollectedLevies, authority.clone());
+/// True if the address is a registered authority that is currently active and not lapsed.
+pub fn is_authority(env: &Env, authority: &Address) -> bool {
+    matches!(
+        get_authority_data(env, authority),
+        Some(data) if data.status == AuthorityStatus::Active && !has_lapsed(env, data.expires_at)
+    )
+}
+
+/// True if an active authority's registration expires within `within` seconds,
+/// including registrations already expired but still inside the grace period.
+pub fn is_expiring(env: &Env, authority: &Address, within: u64) -> bool {
+    match get_authority_data(env, authority) {
+        Some(RegisteredAuthorityData {
+            status: AuthorityStatus::Active,
+            expires_at: Some(expires_at),
+            ..
+        }) => !has_lapsed(env, Some(expires_at)) && expires_at <= env.ledger().timestamp().saturating_add(within),
+        _ => false,
+    }
+}
+
+/// Get collected levy amount for an authority in `token`
+pub fn get_collected_levy(env: &Env, authority: &Address, token: &Address) -> i128 {
+    let key = (DataKey::CollectedLevies, authority.clone(), token.clone());
     env.storage().persistent().get(&key).unwrap_or(0)
 }
 
-/// Set collected levy amount for an authority
-pub fn set_collected_levy(env: &Env, authority: &Address, amount: &i128) {
-    let key = (DataKey::CollectedLevies, authority.clone());
+/// Set collected levy amount for an authority in `token`
+pub fn set_collected_levy(env: &Env, authority: &Address, token: &Address, amount: &i128) {
+    let key = (DataKey::CollectedLevies, authority.clone(), token.clone());
     env.storage().persistent().set(&key, amount);
 }
 
-/// Update collected levy amount for an authority (add to existing)
-pub fn update_collected_levy(env: &Env, authority: &Address, additional_amount: &i128) {
-    let current = get_collected_levy(env, authority);
+/// Update collected levy amount for an authority in `token` (add to existing)
+pub fn update_collected_levy(env: &Env, authority: &Address, token: &Address, additional_amount: &i128) {
+    let current = get_collected_levy(env, authority, token);
     let new_amount = current + additional_amount;
-    set_collected_levy(env, authority, &new_amount);
+    set_collected_levy(env, authority, token, &new_amount);
+
+    let mut tokens = get_levy_tokens(env, authority);
+    if !tokens.contains(token) {
+        tokens.push_back(token.clone());
+        env.storage()
+            .persistent()
+            .set(&(DataKey::LevyTokens, authority.clone()), &tokens);
+    }
+}
+
+/// Tokens an authority holds collected levies in
+pub fn get_levy_tokens(env: &Env, authority: &Address) -> Vec<Address> {
+    let key = (DataKey::LevyTokens, authority.clone());
+    env.storage().persistent().get(&key).unwrap_or(Vec::new(env))
 }
 
-/// Remove collected levy entry for an authority  
-pub fn remove_collected_levy(env: &Env, authority: &Address) {
+/// Removes and returns a levy balance stored per authority only, before levies
+/// were tracked per token.
+pub fn take_untokened_collected_levy(env: &Env, authority: &Address) -> Option<i128> {
     let key = (DataKey::CollectedLevies, authority.clone());
+    let levy = env.storage().persistent().get(&key)?;
     env.storage().persistent().remove(&key);
+    Some(levy)
 }
 
-/// Get collected fees amount for an authority
-pub fn get_collected_fees(env: &Env, authority: &Address) -> i128 {
-    let key = (DataKey::CollectedFees, authority.clone());
-    env.storage().persistent().get(&key).unwrap_or(0)
+/// Reads an authority registered under the legacy `RegAuthPrefix` key.
+pub fn get_legacy_authority_data(env: &Env, authority: &Address) -> Option<LegacyAuthorityData> {
+    let key = (DataKey::RegAuthPrefix, authority.clone());
+    env.storage().persistent().get(&key)
 }
 
-/// Update collected fees amount for an authority (add to existing)
-pub fn update_collected_fees(env: &Env, authority: &Address, additional_amount: &i128) {
-    let current = get_collected_fees(env, authority);
-    let new_amount = current + additional_amount;
-    let key = (DataKey::CollectedFees, authority.clone());
-    env.storage().persistent().set(&key, &new_amount);
+/// Removes an authority registered under the legacy `RegAuthPrefix` key.
+pub fn remove_legacy_authority_data(env: &Env, authority: &Address) {
+    let key = (DataKey::RegAuthPrefix, authority.clone());
+    env.storage().persistent().remove(&key);
+}
+
+/// Reads a levy balance stored under the legacy `CollLevyPrefix` key.
+pub fn get_legacy_collected_levy(env: &Env, authority: &Address) -> Option<i128> {
+    let key = (DataKey::CollLevyPrefix, authority.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Reads a payment record still in the layout of the original deployment.
+///
+/// `None` if the payer has no record or its record is already in the current layout.
+pub fn get_legacy_payment_record(env: &Env, payer: &Address) -> Option<LegacyPaymentRecord> {
+    let key = (DataKey::PaymentRecord, payer.clone());
+    let raw: Map<Symbol, Val> = env.storage().persistent().get(&key)?;
+    // Records in the current layout carry their position in the payment log
+    if raw.contains_key(symbol_short!("sequence")) {
+        return None;
+    }
+    LegacyPaymentRecord::try_from_val(env, raw.as_val()).ok()
+}
+
+/// Removes and returns authority data stored under `DataKey::Authority` in the layout
+/// of older deployments, with free-form metadata and no status, expiry or level.
+///
+/// The registry index is left untouched; the caller writes the record back in the current layout.
+pub fn take_unprofiled_authority_data(env: &Env, authority: &Address) -> Option<LegacyAuthorityData> {
+    let key = (DataKey::Authority, authority.clone());
+    let raw: Map<Symbol, Val> = env.storage().persistent().get(&key)?;
+    // Records in the current layout carry a typed profile
+    if raw.contains_key(symbol_short!("profile")) {
+        return None;
+    }
+    let data = LegacyAuthorityData::try_from_val(env, raw.as_val()).ok()?;
+    env.storage().persistent().remove(&key);
+    Some(data)
+}
+
+/// Removes a levy balance stored under the legacy `CollLevyPrefix` key.
+pub fn remove_legacy_collected_levy(env: &Env, authority: &Address) {
+    let key = (DataKey::CollLevyPrefix, authority.clone());
+    env.storage().persistent().remove(&key);
+}
+
+/// Remove collected levy entry for an authority in `token`
+pub fn remove_collected_levy(env: &Env, authority: &Address, token: &Address) {
+    let key = (DataKey::CollectedLevies, authority.clone(), token.clone());
+    env.storage().persistent().remove(&key);
+
+    let tokens_key = (DataKey::LevyTokens, authority.clone());
+    let mut tokens = get_levy_tokens(env, authority);
+    if let Some(index) = tokens.first_index_of(token) {
+        tokens.remove(index);
+        if tokens.is_empty() {
+            env.storage().persistent().remove(&tokens_key);
+        } else {
+            env.storage().persistent().set(&tokens_key, &tokens);
+        }
+    }
 }
 
 /// Get token contract ID from storage
@@ -183,3 +857,223 @@ pub fn set_token_id(env: &Env, token_id: &Address) {
 pub fn set_token_wasm_hash(env: &Env, wasm_hash: &BytesN<32>) {
     env.storage().instance().set(&DataKey::TokenWasmHash, wasm_hash);
 }
+
+/// Reads the protocol contract allowed to call the resolver hooks.
+pub fn get_protocol_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::ProtocolContract)
+}
+
+/// Writes the protocol contract allowed to call the resolver hooks.
+pub fn set_protocol_contract(env: &Env, protocol: &Address) {
+    env.storage().instance().set(&DataKey::ProtocolContract, protocol);
+}
+
+/// True if `attester` may issue verification attestations.
+pub fn is_platform_attester(env: &Env, attester: &Address) -> bool {
+    let key = (DataKey::PlatformAttester, attester.clone());
+    env.storage().persistent().has(&key)
+}
+
+/// Trusts `attester` to issue verification attestations with appropriate TTL.
+pub fn set_platform_attester(env: &Env, attester: &Address) {
+    let key = (DataKey::PlatformAttester, attester.clone());
+    env.storage().persistent().set(&key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, env.storage().max_ttl() - 100, env.storage().max_ttl());
+}
+
+/// Stops trusting `attester`.
+pub fn remove_platform_attester(env: &Env, attester: &Address) {
+    let key = (DataKey::PlatformAttester, attester.clone());
+    env.storage().persistent().remove(&key);
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Sponsor Nominations
+// ══════════════════════════════════════════════════════════════════════════════
+
+/// Seconds a nominee has to accept a sponsored registration (7 days)
+pub const NOMINATION_PERIOD: u64 = 7 * 24 * 60 * 60;
+
+/// Reference id of the pending payment recorded for `register_authority` applications;
+/// the platform attests these applicants under the same ref id
+pub const PUBLIC_REGISTRATION_REF_ID: &str = "public_registration";
+
+/// Reads the pending nomination of a nominee, including an expired one.
+pub fn get_nomination(env: &Env, nominee: &Address) -> Option<AuthorityNomination> {
+    let key = (DataKey::Nomination, nominee.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Writes the pending nomination of its nominee.
+pub fn set_nomination(env: &Env, nomination: &AuthorityNomination) {
+    set_persistent(env, &(DataKey::Nomination, nomination.nominee.clone()), nomination);
+}
+
+/// Removes the pending nomination of a nominee.
+pub fn remove_nomination(env: &Env, nominee: &Address) {
+    let key = (DataKey::Nomination, nominee.clone());
+    env.storage().persistent().remove(&key);
+}
+
+/// Reads the sponsor of an authority, None if it was not registered through a nomination.
+pub fn get_sponsor(env: &Env, authority: &Address) -> Option<Address> {
+    let key = (DataKey::Sponsor, authority.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Records the sponsor of an authority.
+pub fn set_sponsor(env: &Env, authority: &Address, sponsor: &Address) {
+    set_persistent(env, &(DataKey::Sponsor, authority.clone()), sponsor);
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Authority Registry Index
+// ══════════════════════════════════════════════════════════════════════════════
+//
+// Each index list is split into pages of `REGISTRY_PAGE_SIZE` addresses so no
+// single entry grows with the registry. Every address also stores its position,
+// which makes membership checks and removals constant-time: a removed address
+// is replaced by the last one, so list order is not stable across removals.
+
+/// Addresses stored per registry index page
+pub const REGISTRY_PAGE_SIZE: u32 = 100;
+
+/// Revoked authorities are dropped from the index; revocation is terminal.
+fn is_indexed(data: &RegisteredAuthorityData) -> bool {
+    data.status != AuthorityStatus::Revoked
+}
+
+/// Adds, moves or drops an authority in the index after its data changed.
+fn update_registry_index(env: &Env, previous: Option<&RegisteredAuthorityData>, data: &RegisteredAuthorityData) {
+    let was_indexed = previous.is_some_and(is_indexed);
+    let now_indexed = is_indexed(data);
+
+    if let Some(previous) = previous.filter(|_| was_indexed) {
+        if !now_indexed || previous.ref_id != data.ref_id {
+            registry_remove(env, &RegistryIndex::RefId(previous.ref_id.clone()), &data.address);
+        }
+        if !now_indexed {
+            registry_remove(env, &RegistryIndex::All, &data.address);
+        }
+    }
+    if now_indexed {
+        registry_add(env, &RegistryIndex::All, &data.address);
+        registry_add(env, &RegistryIndex::RefId(data.ref_id.clone()), &data.address);
+    }
+}
+
+/// Indexes an authority stored before the index existed; a no-op if it is already indexed.
+pub fn ensure_indexed(env: &Env, authority: &Address) {
+    if let Some(data) = get_authority_data(env, authority) {
+        update_registry_index(env, None, &data);
+    }
+}
+
+/// Number of addresses in an index list.
+pub fn registry_len(env: &Env, index: &RegistryIndex) -> u32 {
+    let key = (DataKey::RegistryLen, index.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Up to `limit` addresses of an index list, starting at position `start`.
+pub fn registry_range(env: &Env, index: &RegistryIndex, start: u32, limit: u32) -> Vec<Address> {
+    let mut result = Vec::new(env);
+    let end = start.saturating_add(limit).min(registry_len(env, index));
+    let mut position = start;
+    while position < end {
+        let page_no = position / REGISTRY_PAGE_SIZE;
+        let page = get_registry_page(env, index, page_no);
+        let page_end = end.min((page_no + 1) * REGISTRY_PAGE_SIZE);
+        for slot in (position % REGISTRY_PAGE_SIZE)..(page_end - page_no * REGISTRY_PAGE_SIZE) {
+            if let Some(address) = page.get(slot) {
+                result.push_back(address);
+            }
+        }
+        position = page_end;
+    }
+    result
+}
+
+fn registry_add(env: &Env, index: &RegistryIndex, address: &Address) {
+    let pos_key = (DataKey::RegistryPos, index.clone(), address.clone());
+    if env.storage().persistent().has(&pos_key) {
+        return;
+    }
+
+    let len = registry_len(env, index);
+    let page_no = len / REGISTRY_PAGE_SIZE;
+    let mut page = get_registry_page(env, index, page_no);
+    page.push_back(address.clone());
+    set_registry_page(env, index, page_no, &page);
+    set_persistent(env, &pos_key, &len);
+    set_registry_len(env, index, len + 1);
+}
+
+fn registry_remove(env: &Env, index: &RegistryIndex, address: &Address) {
+    let pos_key = (DataKey::RegistryPos, index.clone(), address.clone());
+    let Some(position) = env.storage().persistent().get::<_, u32>(&pos_key) else {
+        return;
+    };
+
+    let last = registry_len(env, index) - 1;
+    let last_page_no = last / REGISTRY_PAGE_SIZE;
+    let mut last_page = get_registry_page(env, index, last_page_no);
+    let Some(last_address) = last_page.pop_back() else {
+        return;
+    };
+
+    // Move the last address into the freed slot
+    if position != last {
+        let page_no = position / REGISTRY_PAGE_SIZE;
+        if page_no == last_page_no {
+            last_page.set(position % REGISTRY_PAGE_SIZE, last_address.clone());
+        } else {
+            let mut page = get_registry_page(env, index, page_no);
+            page.set(position % REGISTRY_PAGE_SIZE, last_address.clone());
+            set_registry_page(env, index, page_no, &page);
+        }
+        set_persistent(env, &(DataKey::RegistryPos, index.clone(), last_address), &position);
+    }
+
+    if last_page.is_empty() {
+        env.storage()
+            .persistent()
+            .remove(&(DataKey::RegistryPage, index.clone(), last_page_no));
+    } else {
+        set_registry_page(env, index, last_page_no, &last_page);
+    }
+    env.storage().persistent().remove(&pos_key);
+    set_registry_len(env, index, last);
+}
+
+fn get_registry_page(env: &Env, index: &RegistryIndex, page_no: u32) -> Vec<Address> {
+    let key = (DataKey::RegistryPage, index.clone(), page_no);
+    env.storage().persistent().get(&key).unwrap_or(Vec::new(env))
+}
+
+fn set_registry_page(env: &Env, index: &RegistryIndex, page_no: u32, page: &Vec<Address>) {
+    set_persistent(env, &(DataKey::RegistryPage, index.clone(), page_no), page);
+}
+
+fn set_registry_len(env: &Env, index: &RegistryIndex, len: u32) {
+    let key = (DataKey::RegistryLen, index.clone());
+    if len == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        set_persistent(env, &key, &len);
+    }
+}
+
+/// Writes a persistent entry with appropriate TTL.
+fn set_persistent<K, V>(env: &Env, key: &K, value: &V)
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val>,
+{
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, env.storage().max_ttl() - 100, env.storage().max_ttl());
+}
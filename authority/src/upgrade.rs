@@ -0,0 +1,154 @@
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Upgrade Module
+// ══════════════════════════════════════════════════════════════════════════════
+//
+// In-place WASM upgrades with a stored contract version.
+//
+// `upgrade` swaps the contract code; the new code then runs its storage
+// migrations once through `migrate`, which records the version it migrated
+// to so the same migrations can never run twice.
+
+use crate::errors::Error;
+use crate::state::{
+    authority_from_legacy, ensure_indexed, get_authority_data, get_contract_version, get_legacy_authority_data,
+    get_legacy_collected_levy, get_legacy_payment_record, get_token_id, payment_from_legacy, record_payment,
+    remove_legacy_authority_data, remove_legacy_collected_levy, set_authority_data, set_contract_version,
+    take_unprofiled_authority_data, take_untokened_collected_levy, update_collected_levy,
+};
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+/// Version of the storage layout this code expects. Bump it together with a
+/// new step in `run_migrations` whenever stored data has to change.
+pub const CONTRACT_VERSION: u32 = 1;
+
+/// Most addresses `migrate_legacy_entries` accepts per call, keeping each call within resource limits
+pub const MAX_MIGRATION_BATCH: u32 = 50;
+
+/// Replaces the contract code with an uploaded WASM
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `admin` - The contract owner (must be authenticated)
+/// * `new_wasm_hash` - Hash of WASM already uploaded to the network
+///
+/// # Returns
+/// * `Ok(())` - If the code was replaced; it takes effect after this invocation
+/// * `Err(Error)` - If not authorized or contract not initialized
+///
+/// # Note
+/// Call `migrate` afterwards so the new code can update stored data.
+pub fn upgrade(env: &Env, admin: &Address, new_wasm_hash: &BytesN<32>) -> Result<(), Error> {
+    crate::require_owner!(env, admin);
+
+    crate::events::contract_upgraded(env, admin, new_wasm_hash, get_contract_version(env));
+
+    env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+
+    Ok(())
+}
+
+/// Runs the storage migrations of every version between the stored one and `CONTRACT_VERSION`
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `admin` - The contract owner (must be authenticated)
+///
+/// # Returns
+/// * `Ok(u32)` - The version the storage was migrated to
+/// * `Err(Error::AlreadyMigrated)` - If the storage is already at `CONTRACT_VERSION`
+/// * `Err(Error)` - If not authorized or contract not initialized
+pub fn migrate(env: &Env, admin: &Address) -> Result<u32, Error> {
+    crate::require_owner!(env, admin);
+
+    let from_version = get_contract_version(env);
+    if from_version >= CONTRACT_VERSION {
+        return Err(Error::AlreadyMigrated);
+    }
+
+    run_migrations(env, from_version);
+    set_contract_version(env, CONTRACT_VERSION);
+
+    crate::events::contract_migrated(env, admin, from_version, CONTRACT_VERSION);
+
+    Ok(CONTRACT_VERSION)
+}
+
+/// Storage changes introduced by each version after `from_version`.
+///
+/// Version 1 only starts recording the version. The registrations and payment
+/// records of the original, unversioned deployment are not rewritten here, since
+/// one call cannot touch every entry: `get_authority_data` and `get_payment_record`
+/// decode their old layout on read, and `migrate_legacy_entries` rewrites them in
+/// batches. Later versions add their steps here, e.g. `if from_version < 2 { ... }`.
+fn run_migrations(_env: &Env, _from_version: u32) {}
+
+/// Moves entries written under the legacy `RegAuthPrefix` / `CollLevyPrefix` keys
+/// to the current `(DataKey::Authority, addr)` / `(DataKey::CollectedLevies, addr)` layout
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `admin` - The contract owner (must be authenticated)
+/// * `addresses` - Accounts to migrate, at most `MAX_MIGRATION_BATCH`
+///
+/// # Returns
+/// * `Ok(u32)` - Number of legacy entries moved
+/// * `Err(Error::MigrationBatchTooLarge)` - If too many addresses are passed
+/// * `Err(Error)` - If not authorized or contract not initialized
+///
+/// # Notes
+/// * Registrations stored by older deployments, under `DataKey::Authority` or the legacy
+///   prefix, become active, permanent, Basic-level authorities with a profile named after
+///   their free-form metadata, at version 1
+/// * A current registration wins over a legacy one, which is then only removed
+/// * Levy balances kept per authority only, under the legacy prefix or under
+///   `(DataKey::CollectedLevies, addr)`, are added to the current balance in the primary fee token
+/// * Payment records in the original layout become the first, approved entry of the
+///   payer's payment log, paid in the primary fee token; they granted eligibility on payment
+/// * Legacy entries are removed once handled, so running a batch again moves nothing
+/// * Every listed authority is added to the registry index if it is missing from it
+pub fn migrate_legacy_entries(env: &Env, admin: &Address, addresses: &Vec<Address>) -> Result<u32, Error> {
+    crate::require_owner!(env, admin);
+    if addresses.len() > MAX_MIGRATION_BATCH {
+        return Err(Error::MigrationBatchTooLarge);
+    }
+
+    let mut migrated = 0u32;
+    for address in addresses.iter() {
+        if let Some(legacy) = take_unprofiled_authority_data(env, &address) {
+            set_authority_data(env, &authority_from_legacy(&address, legacy));
+            migrated += 1;
+        }
+
+        if let Some(legacy) = get_legacy_authority_data(env, &address) {
+            if get_authority_data(env, &address).is_none() {
+                set_authority_data(env, &authority_from_legacy(&address, legacy));
+                migrated += 1;
+            }
+            remove_legacy_authority_data(env, &address);
+        }
+        ensure_indexed(env, &address);
+
+        if let Some(legacy) = get_legacy_payment_record(env, &address) {
+            let token = get_token_id(env).ok_or(Error::NotInitialized)?;
+            record_payment(env, &payment_from_legacy(legacy, token));
+            migrated += 1;
+        }
+
+        if let Some(levy) = take_untokened_collected_levy(env, &address) {
+            let token = get_token_id(env).ok_or(Error::NotInitialized)?;
+            update_collected_levy(env, &address, &token, &levy);
+            migrated += 1;
+        }
+
+        if let Some(levy) = get_legacy_collected_levy(env, &address) {
+            let token = get_token_id(env).ok_or(Error::NotInitialized)?;
+            update_collected_levy(env, &address, &token, &levy);
+            remove_legacy_collected_levy(env, &address);
+            migrated += 1;
+        }
+    }
+
+    crate::events::legacy_entries_migrated(env, admin, migrated);
+
+    Ok(migrated)
+}
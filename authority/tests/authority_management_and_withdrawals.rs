@@ -8,7 +8,9 @@ use soroban_sdk::{
     BytesN,
     Env,
     IntoVal,
+    Map,
     String as SorobanString,
+    TryFromVal,
 };
 
 // Import types AND CONSTANTS from the contract crate
@@ -17,16 +19,25 @@ use authority::*; // Import everything including Error, structs, and event const
 // Constants for fees and amounts (using stroops)
 const REGISTRATION_FEE: i128 = 100_0000000; // 100 XLM
 const DEFAULT_LEVY: i128 = 5_0000000; // 5 XLM
-
-// TODO: Schema-related functionality removed - this resolver focuses on authority registration
-// Schema management is handled by other resolver types
-const MINT_AMOUNT: i128 = 1_000_0000000; // 1000 XLM for testing
+const MINT_AMOUNT: i128 = 1000_0000000; // 1000 XLM for testing
 
 // Helper function to create a dummy token wasm hash for tests
 fn create_dummy_token_wasm_hash(env: &Env) -> BytesN<32> {
     BytesN::from_array(env, &[0u8; 32])
 }
 
+// Helper function to create a profile with only a display name
+fn test_profile(env: &Env, name: &str) -> AuthorityProfile {
+    AuthorityProfile {
+        name: SorobanString::from_str(env, name),
+        website: None,
+        jurisdiction: None,
+        registry_id: None,
+        contact: None,
+        documents_hash: None,
+    }
+}
+
 // Helper function to create and register a token contract
 fn create_token_contract<'a>(
     env: &Env,
@@ -58,6 +69,7 @@ struct TestSetup<'a> {
     token_admin_client: token::StellarAssetClient<'a>,
     resolver_address: Address,
     resolver_client: AuthorityResolverContractClient<'a>,
+    protocol: Address,
 }
 
 // Helper function to simulate contract's token balance after levy collection - REMOVED
@@ -90,6 +102,7 @@ fn setup_env<'a>(mock_auths: bool) -> TestSetup<'a> {
     });
 
     let admin = Address::generate(&env);
+    let protocol = Address::generate(&env);
 
     // Create the mock token contract
     let (token_address, token_client, token_admin_client) = create_token_contract(&env, &admin);
@@ -111,7 +124,7 @@ fn setup_env<'a>(mock_auths: bool) -> TestSetup<'a> {
             },
         }]);
     }
-    resolver_client.initialize(&admin, &token_address, &create_dummy_token_wasm_hash(&env));
+    resolver_client.initialize(&admin, &token_address, &create_dummy_token_wasm_hash(&env), &protocol);
 
     TestSetup {
         env,
@@ -121,6 +134,7 @@ fn setup_env<'a>(mock_auths: bool) -> TestSetup<'a> {
         token_admin_client,
         resolver_address,
         resolver_client,
+        protocol,
     }
 }
 
@@ -147,6 +161,25 @@ fn create_dummy_attestation(
     }
 }
 
+// Helper function for schema rules that accept any active authority
+fn open_schema_rules(env: &Env) -> SchemaRules {
+    SchemaRules {
+        allowed_authorities: soroban_sdk::Vec::new(env),
+        min_level: VerificationLevel::Basic,
+        levy_amount: None,
+        levy_recipient: None,
+        revocable: true,
+        max_expiration: None,
+    }
+}
+
+// Helper function to register a schema with open rules
+fn register_open_schema(setup: &TestSetup, schema_uid: &BytesN<32>) {
+    setup
+        .resolver_client
+        .admin_register_schema(&setup.admin, schema_uid, &open_schema_rules(&setup.env));
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // ► Tests Initialization Logic
 // ══════════════════════════════════════════════════════════════════════════════
@@ -155,10 +188,15 @@ fn test_initialize() {
     let setup = setup_env(true);
     assert_eq!(setup.resolver_client.get_admin_address(), setup.admin.clone());
     assert_eq!(setup.resolver_client.get_token_id(), setup.token_address.clone());
+    assert_eq!(
+        setup.resolver_client.get_protocol_contract(),
+        Some(setup.protocol.clone())
+    );
     let reinit_result = setup.resolver_client.try_initialize(
         &setup.admin,
         &setup.token_address,
         &create_dummy_token_wasm_hash(&setup.env),
+        &setup.protocol,
     );
     // Check inner Result is Err(Ok(ContractError))
     assert!(matches!(reinit_result.err().unwrap(), Ok(Error::AlreadyInitialized)));
@@ -185,7 +223,7 @@ fn test_register_authority_with_fee() {
     let setup = setup_env(true);
     let caller = Address::generate(&setup.env);
     let authority_to_register = Address::generate(&setup.env);
-    let metadata = SorobanString::from_str(&setup.env, "Test Authority Metadata");
+    let metadata = test_profile(&setup.env, "Test Authority Metadata");
     let fee = REGISTRATION_FEE;
 
     // Mint and Approve
@@ -209,12 +247,50 @@ fn test_register_authority_with_fee() {
         "No events were recorded after register_authority. Events: {:?}",
         events
     );
+    assert!(has_event(&setup.env, AUTHORITY_NOMINATED));
+
+    // Sponsoring someone else only nominates them until they consent
+    assert!(
+        !setup.resolver_client.is_authority(&authority_to_register),
+        "Nominee should not be registered before accepting"
+    );
+    let nomination = setup
+        .resolver_client
+        .get_authority_nomination(&authority_to_register)
+        .unwrap();
+    assert_eq!(nomination.sponsor, caller);
+    assert_eq!(nomination.amount_paid, fee);
+    assert_eq!(setup.resolver_client.get_fee_ledger().total_collected, 0);
+
+    setup
+        .resolver_client
+        .accept_authority_nomination(&authority_to_register);
+    assert!(has_event(&setup.env, NOMINATION_ACCEPTED));
 
-    // Verify authority is registered (after event check)
+    // Consent only files the nominee's application; registration waits for review
     assert!(
-        setup.resolver_client.is_authority(&authority_to_register),
-        "Authority should be registered"
+        !setup.resolver_client.is_authority(&authority_to_register),
+        "Nominee should not be registered without a platform attestation"
     );
+    let payment = setup
+        .resolver_client
+        .get_payment_record(&authority_to_register)
+        .unwrap();
+    assert_eq!(payment.status, PaymentStatus::Pending);
+    assert_eq!(payment.amount_paid, fee);
+    assert_eq!(
+        payment.ref_id,
+        SorobanString::from_str(&setup.env, PUBLIC_REGISTRATION_REF_ID)
+    );
+    assert_eq!(
+        setup.resolver_client.get_authority_sponsor(&authority_to_register),
+        Some(caller.clone())
+    );
+    assert!(setup
+        .resolver_client
+        .get_authority_nomination(&authority_to_register)
+        .is_none());
+    assert_eq!(setup.resolver_client.get_fee_ledger().total_collected, fee);
 
     // Verify fee transfer (after event check)
     let contract_balance = setup.token_client.balance(&setup.resolver_address);
@@ -230,7 +306,7 @@ fn test_register_authority_event_only() {
     let setup = setup_env(true); // Use shared env with mock_all_auths
     let caller = Address::generate(&setup.env);
     let authority_to_register = Address::generate(&setup.env);
-    let metadata = SorobanString::from_str(&setup.env, "Event Test");
+    let metadata = test_profile(&setup.env, "Event Test");
     let fee = REGISTRATION_FEE;
 
     // Ensure caller has funds and approves (mock_all_auths handles auth requirements)
@@ -279,7 +355,7 @@ fn test_register_authority_no_allowance() {
     let setup = setup_env(false);
     let caller = Address::generate(&setup.env);
     let authority_to_register = Address::generate(&setup.env);
-    let metadata = SorobanString::from_str(&setup.env, "Test Authority No Allowance");
+    let metadata = test_profile(&setup.env, "Test Authority No Allowance");
     let fee = REGISTRATION_FEE;
 
     // Mock for minting (admin auth)
@@ -325,7 +401,7 @@ fn test_register_authority_insufficient_funds() {
     let setup = setup_env(true);
     let caller = Address::generate(&setup.env);
     let authority_to_reg = Address::generate(&setup.env);
-    let metadata = SorobanString::from_str(&setup.env, "Test Authority");
+    let metadata = test_profile(&setup.env, "Test Authority");
 
     // DO NOT mint tokens to the caller
 
@@ -343,6 +419,105 @@ fn test_register_authority_insufficient_funds() {
         .register_authority(&caller, &authority_to_reg, &metadata);
 }
 
+#[test]
+fn test_self_registration_awaits_review_and_attestation() {
+    let setup = setup_env(true);
+    let authority = Address::generate(&setup.env);
+    setup.token_admin_client.mint(&authority, &REGISTRATION_FEE);
+
+    setup
+        .resolver_client
+        .register_authority(&authority, &authority, &test_profile(&setup.env, "Self Registered"));
+    assert!(has_event(&setup.env, PAYMENT_RECEIVED));
+
+    // Paying only files an application
+    assert!(!setup.resolver_client.is_authority(&authority));
+    let payment = setup.resolver_client.get_payment_record(&authority).unwrap();
+    assert_eq!(payment.status, PaymentStatus::Pending);
+    assert_eq!(
+        payment.ref_id,
+        SorobanString::from_str(&setup.env, PUBLIC_REGISTRATION_REF_ID)
+    );
+    assert!(setup.resolver_client.get_authority_sponsor(&authority).is_none());
+    assert_eq!(setup.resolver_client.get_fee_ledger().total_collected, REGISTRATION_FEE);
+
+    // Approval alone does not register either; a platform attestation must resolve
+    setup.resolver_client.approve_payment(&setup.admin, &authority);
+    assert!(!setup.resolver_client.is_authority(&authority));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_accept_nomination_requires_nominee_auth() {
+    let setup = setup_env(true);
+    let sponsor = Address::generate(&setup.env);
+    let nominee = Address::generate(&setup.env);
+    setup.token_admin_client.mint(&sponsor, &REGISTRATION_FEE);
+    setup
+        .resolver_client
+        .register_authority(&sponsor, &nominee, &test_profile(&setup.env, "Nominee"));
+
+    // The nominee has not consented
+    setup.env.set_auths(&[]);
+    setup.resolver_client.accept_authority_nomination(&nominee);
+}
+
+#[test]
+fn test_expired_nomination_refunds_sponsor() {
+    let setup = setup_env(true);
+    let sponsor = Address::generate(&setup.env);
+    let other_sponsor = Address::generate(&setup.env);
+    let nominee = Address::generate(&setup.env);
+    let metadata = test_profile(&setup.env, "Nominee");
+    setup.token_admin_client.mint(&sponsor, &REGISTRATION_FEE);
+    setup.token_admin_client.mint(&other_sponsor, &(REGISTRATION_FEE * 2));
+
+    setup.resolver_client.register_authority(&sponsor, &nominee, &metadata);
+    assert_eq!(setup.token_client.balance(&sponsor), 0);
+
+    // One pending nomination per nominee
+    let result = setup
+        .resolver_client
+        .try_register_authority(&other_sponsor, &nominee, &metadata);
+    assert!(matches!(result, Err(Ok(Error::NominationPending))));
+
+    // Still within the acceptance window
+    let result = setup.resolver_client.try_expire_authority_nomination(&nominee);
+    assert!(matches!(result, Err(Ok(Error::NominationNotExpired))));
+
+    let expires_at = setup
+        .resolver_client
+        .get_authority_nomination(&nominee)
+        .unwrap()
+        .expires_at;
+    setup.env.ledger().with_mut(|li| li.timestamp = expires_at + 1);
+
+    let result = setup.resolver_client.try_accept_authority_nomination(&nominee);
+    assert!(matches!(result, Err(Ok(Error::NominationExpired))));
+    assert!(!setup.resolver_client.is_authority(&nominee));
+
+    // Anyone can close it; the fee goes back to the sponsor
+    let refunded = setup.resolver_client.expire_authority_nomination(&nominee);
+    assert!(has_event(&setup.env, NOMINATION_EXPIRED));
+    assert_eq!(refunded, REGISTRATION_FEE);
+    assert_eq!(setup.token_client.balance(&sponsor), REGISTRATION_FEE);
+    assert!(setup.resolver_client.get_authority_nomination(&nominee).is_none());
+    assert_eq!(setup.resolver_client.get_fee_ledger().total_collected, 0);
+
+    let result = setup.resolver_client.try_expire_authority_nomination(&nominee);
+    assert!(matches!(result, Err(Ok(Error::NominationNotFound))));
+
+    // The nominee can be nominated again and accept this time
+    setup
+        .resolver_client
+        .register_authority(&other_sponsor, &nominee, &metadata);
+    setup.resolver_client.accept_authority_nomination(&nominee);
+    assert_eq!(
+        setup.resolver_client.get_authority_sponsor(&nominee),
+        Some(other_sponsor)
+    );
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // ► Tests Admin Functions (Authority & Schema Registration)
 // ══════════════════════════════════════════════════════════════════════════════
@@ -351,7 +526,7 @@ fn test_admin_register_authority() {
     // Revert to setup_env(true), remove specific mocks
     let setup = setup_env(true);
     let authority_to_reg = Address::generate(&setup.env);
-    let metadata = SorobanString::from_str(&setup.env, "Admin Registered");
+    let metadata = test_profile(&setup.env, "Admin Registered");
 
     // Admin registers an authority directly (mock_all_auths handles admin auth)
     setup
@@ -370,23 +545,99 @@ fn test_admin_register_authority() {
     */
 }
 
-/*
 #[test]
 fn test_admin_register_schema() {
-    // TODO: Schema registration moved to dedicated schema resolver
-    // RECOMMENDATION: Use separate schema resolver for schema management
-    // IMPACT: This authority resolver focuses only on authority registration
+    let setup = setup_env(true);
+    let schema_uid = BytesN::random(&setup.env);
+    let levy_recipient = Address::generate(&setup.env);
+    setup.resolver_client.admin_register_authority(
+        &setup.admin,
+        &levy_recipient,
+        &test_profile(&setup.env, "Schema Owner"),
+    );
+    assert_eq!(setup.resolver_client.get_schema_rules(&schema_uid), None);
+
+    let rules = SchemaRules {
+        levy_amount: Some(DEFAULT_LEVY),
+        levy_recipient: Some(levy_recipient),
+        max_expiration: Some(3600),
+        ..open_schema_rules(&setup.env)
+    };
+    setup
+        .resolver_client
+        .admin_register_schema(&setup.admin, &schema_uid, &rules);
+
+    let events = setup.env.events().all();
+    let registered = events.iter().any(|(_, topics, _)| {
+        topics
+            .get(0)
+            .and_then(|v| soroban_sdk::Symbol::try_from_val(&setup.env, &v).ok())
+            == Some(SCHEMA_REGISTERED)
+    });
+    assert!(registered, "SCHEMA_REGISTERED event not found. Events: {:?}", events);
+    assert_eq!(setup.resolver_client.get_schema_rules(&schema_uid), Some(rules));
+
+    // Only the owner can register schemas
+    let non_admin = Address::generate(&setup.env);
+    let result =
+        setup
+            .resolver_client
+            .try_admin_register_schema(&non_admin, &schema_uid, &open_schema_rules(&setup.env));
+    assert!(matches!(result.err().unwrap(), Ok(Error::NotAuthorized)));
 }
-*/
 
-/*
 #[test]
 fn test_admin_register_schema_invalid_rules() {
-    // TODO: Schema validation moved to dedicated schema resolver
-    // RECOMMENDATION: Schema rules validation is handled by schema resolvers
-    // IMPACT: Authority resolver doesn't need schema rule validation
+    let setup = setup_env(true);
+    let schema_uid = BytesN::random(&setup.env);
+    let recipient = Address::generate(&setup.env);
+
+    // Levy without a recipient
+    let rules = SchemaRules {
+        levy_amount: Some(DEFAULT_LEVY),
+        ..open_schema_rules(&setup.env)
+    };
+    let result = setup
+        .resolver_client
+        .try_admin_register_schema(&setup.admin, &schema_uid, &rules);
+    assert!(matches!(result.err().unwrap(), Ok(Error::InvalidSchemaRules)));
+
+    // Levy recipient must be an authority
+    let rules = SchemaRules {
+        levy_amount: Some(DEFAULT_LEVY),
+        levy_recipient: Some(recipient.clone()),
+        ..open_schema_rules(&setup.env)
+    };
+    let result = setup
+        .resolver_client
+        .try_admin_register_schema(&setup.admin, &schema_uid, &rules);
+    assert!(matches!(result.err().unwrap(), Ok(Error::RecipientNotAuthority)));
+
+    // Non-positive levy
+    setup
+        .resolver_client
+        .admin_register_authority(&setup.admin, &recipient, &test_profile(&setup.env, "Schema Owner"));
+    let rules = SchemaRules {
+        levy_amount: Some(0),
+        levy_recipient: Some(recipient),
+        ..open_schema_rules(&setup.env)
+    };
+    let result = setup
+        .resolver_client
+        .try_admin_register_schema(&setup.admin, &schema_uid, &rules);
+    assert!(matches!(result.err().unwrap(), Ok(Error::InvalidSchemaRules)));
+
+    // Zero expiration window
+    let rules = SchemaRules {
+        max_expiration: Some(0),
+        ..open_schema_rules(&setup.env)
+    };
+    let result = setup
+        .resolver_client
+        .try_admin_register_schema(&setup.admin, &schema_uid, &rules);
+    assert!(matches!(result.err().unwrap(), Ok(Error::InvalidSchemaRules)));
+    assert_eq!(setup.resolver_client.get_schema_rules(&schema_uid), None);
 }
-*/
 
 // ══════════════════════════════════════════════════════════════════════════════
 // ► Tests Attest & Revoke Hooks
@@ -398,11 +649,11 @@ fn test_attest_hook_basic() {
     let schema_uid = BytesN::random(&setup.env);
 
     // Register authority (admin way)
-    setup.resolver_client.admin_register_authority(
-        &setup.admin,
-        &authority,
-        &SorobanString::from_str(&setup.env, "Attester"),
-    );
+    setup
+        .resolver_client
+        .admin_register_authority(&setup.admin, &authority, &test_profile(&setup.env, "Attester"));
+
+    register_open_schema(&setup, &schema_uid);
 
     // Mint tokens to authority (although not needed for this test)
     setup.token_admin_client.mint(&authority, &MINT_AMOUNT);
@@ -417,14 +668,97 @@ fn test_attest_hook_basic() {
     assert_eq!(setup.token_client.balance(&setup.resolver_address), 0);
 }
 
-/*
+// Registers an attester and a levy recipient, and a schema charging DEFAULT_LEVY to the recipient
+fn setup_levy_schema(setup: &TestSetup) -> (Address, Address, BytesN<32>) {
+    let attester = Address::generate(&setup.env);
+    let recipient = Address::generate(&setup.env);
+    let schema_uid = BytesN::random(&setup.env);
+    for addr in [&attester, &recipient] {
+        setup
+            .resolver_client
+            .admin_register_authority(&setup.admin, addr, &test_profile(&setup.env, "Authority"));
+    }
+    let rules = SchemaRules {
+        levy_amount: Some(DEFAULT_LEVY),
+        levy_recipient: Some(recipient.clone()),
+        ..open_schema_rules(&setup.env)
+    };
+    setup
+        .resolver_client
+        .admin_register_schema(&setup.admin, &schema_uid, &rules);
+    (attester, recipient, schema_uid)
+}
+
 #[test]
 fn test_attest_hook_with_levy() {
-    // TODO: Levy collection moved to fee collection resolver
-    // RECOMMENDATION: Use fee collection resolver for levy management
-    // IMPACT: This authority resolver focuses on authority verification only
+    let setup = setup_env(true);
+    let (attester, recipient, schema_uid) = setup_levy_schema(&setup);
+    setup.token_admin_client.mint(&attester, &MINT_AMOUNT);
+
+    let attestation = create_dummy_attestation(&setup.env, &attester, &schema_uid, None);
+    assert!(setup.resolver_client.attest(&attestation));
+
+    let events = setup.env.events().all();
+    let collected = events.iter().any(|(_, topics, _)| {
+        topics
+            .get(0)
+            .and_then(|v| soroban_sdk::Symbol::try_from_val(&setup.env, &v).ok())
+            == Some(LEVY_COLLECTED)
+    });
+    assert!(collected, "LEVY_COLLECTED event not found. Events: {:?}", events);
+
+    assert_eq!(setup.token_client.balance(&attester), MINT_AMOUNT - DEFAULT_LEVY);
+    assert_eq!(setup.token_client.balance(&setup.resolver_address), DEFAULT_LEVY);
+    assert_eq!(
+        setup
+            .resolver_client
+            .get_collected_levies(&recipient, &setup.token_address),
+        DEFAULT_LEVY
+    );
+    assert_eq!(setup.resolver_client.get_fee_ledger().total_collected, 0);
+
+    setup.resolver_client.withdraw_levies(&recipient, &setup.token_address);
+    assert_eq!(setup.token_client.balance(&recipient), DEFAULT_LEVY);
+    assert_eq!(
+        setup
+            .resolver_client
+            .get_collected_levies(&recipient, &setup.token_address),
+        0
+    );
+}
+
+#[test]
+fn test_attest_hook_levy_protocol_share() {
+    let setup = setup_env(true);
+    let (attester, recipient, schema_uid) = setup_levy_schema(&setup);
+    setup.token_admin_client.mint(&attester, &MINT_AMOUNT);
+
+    let result = setup.resolver_client.try_set_levy_protocol_share(&setup.admin, &10_001);
+    assert!(matches!(result.err().unwrap(), Ok(Error::InvalidProtocolShare)));
+    let result = setup.resolver_client.try_set_levy_protocol_share(&recipient, &1_000);
+    assert!(matches!(result.err().unwrap(), Ok(Error::NotAuthorized)));
+
+    // 10% of every levy goes to the platform
+    setup.resolver_client.set_levy_protocol_share(&setup.admin, &1_000);
+    assert_eq!(setup.resolver_client.get_levy_protocol_share(), 1_000);
+
+    let attestation = create_dummy_attestation(&setup.env, &attester, &schema_uid, None);
+    setup.resolver_client.attest(&attestation);
+
+    let protocol_cut = DEFAULT_LEVY / 10;
+    assert_eq!(
+        setup
+            .resolver_client
+            .get_collected_levies(&recipient, &setup.token_address),
+        DEFAULT_LEVY - protocol_cut
+    );
+    assert_eq!(setup.resolver_client.get_fee_ledger().total_collected, protocol_cut);
+
+    setup
+        .resolver_client
+        .admin_withdraw_fees(&setup.admin, &setup.token_address, &protocol_cut);
+    assert_eq!(setup.token_client.balance(&setup.admin), protocol_cut);
 }
-*/
 
 #[test]
 fn test_attest_hook_not_authority() {
@@ -441,24 +775,85 @@ fn test_attest_hook_not_authority() {
     assert!(matches!(result.err().unwrap(), Ok(Error::AttesterNotAuthority)));
 }
 
-/*
 #[test]
 fn test_attest_hook_schema_not_registered() {
-    // TODO: Schema validation handled by protocol, not resolver
-    // RECOMMENDATION: Protocol contract validates schema existence
-    // IMPACT: Authority resolver only checks authority status
+    let setup = setup_env(true);
+    let authority = Address::generate(&setup.env);
+    let schema_uid = BytesN::random(&setup.env);
+    setup
+        .resolver_client
+        .admin_register_authority(&setup.admin, &authority, &test_profile(&setup.env, "Attester"));
+
+    // DO NOT register schema_uid
+
+    let attestation = create_dummy_attestation(&setup.env, &authority, &schema_uid, None);
+    let result = setup.resolver_client.try_attest(&attestation);
+    assert!(matches!(result.err().unwrap(), Ok(Error::SchemaNotRegistered)));
+}
+
+#[test]
+fn test_attest_hook_enforces_schema_rules() {
+    let setup = setup_env(true);
+    let authority = Address::generate(&setup.env);
+    let other_authority = Address::generate(&setup.env);
+    let schema_uid = BytesN::random(&setup.env);
+    for addr in [&authority, &other_authority] {
+        setup
+            .resolver_client
+            .admin_register_authority(&setup.admin, addr, &test_profile(&setup.env, "Attester"));
+    }
+    let rules = SchemaRules {
+        allowed_authorities: soroban_sdk::vec![&setup.env, authority.clone()],
+        revocable: false,
+        max_expiration: Some(3600),
+        ..open_schema_rules(&setup.env)
+    };
+    setup
+        .resolver_client
+        .admin_register_schema(&setup.admin, &schema_uid, &rules);
+
+    let mut attestation = create_dummy_attestation(&setup.env, &authority, &schema_uid, None);
+    attestation.revocable = false;
+    attestation.expiration_time = Some(attestation.time + 3600);
+    assert!(setup.resolver_client.attest(&attestation));
+
+    // Attester not on the allow-list
+    let mut other = attestation.clone();
+    other.attester = other_authority;
+    let result = setup.resolver_client.try_attest(&other);
+    assert!(matches!(result.err().unwrap(), Ok(Error::UnauthorizedVerifier)));
+
+    // Revocable attestation on a non-revocable schema
+    let mut revocable = attestation.clone();
+    revocable.revocable = true;
+    let result = setup.resolver_client.try_attest(&revocable);
+    assert!(matches!(result.err().unwrap(), Ok(Error::SchemaNotRevocable)));
+    let result = setup.resolver_client.try_revoke(&attestation);
+    assert!(matches!(result.err().unwrap(), Ok(Error::SchemaNotRevocable)));
+
+    // Expiration beyond the window, or missing
+    let mut too_long = attestation.clone();
+    too_long.expiration_time = Some(attestation.time + 3601);
+    let result = setup.resolver_client.try_attest(&too_long);
+    assert!(matches!(result.err().unwrap(), Ok(Error::InvalidExpiration)));
+    let mut no_expiry = attestation.clone();
+    no_expiry.expiration_time = None;
+    let result = setup.resolver_client.try_attest(&no_expiry);
+    assert!(matches!(result.err().unwrap(), Ok(Error::InvalidExpiration)));
 }
-*/
 
-/*
 #[test]
 #[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
 fn test_attest_hook_with_levy_no_allowance() {
-    // TODO: Levy collection moved to fee collection resolver
-    // RECOMMENDATION: Use fee collection resolver for allowance validation
-    // IMPACT: Authority resolver doesn't handle token transfers
+    let setup = setup_env(true);
+    let (attester, _recipient, schema_uid) = setup_levy_schema(&setup);
+    setup.token_admin_client.mint(&attester, &MINT_AMOUNT);
+
+    // The attester has not authorized the levy transfer
+    setup.env.set_auths(&[]);
+    let attestation = create_dummy_attestation(&setup.env, &attester, &schema_uid, None);
+    setup.resolver_client.attest(&attestation);
 }
-*/
 
 #[test]
 fn test_revoke_hook() {
@@ -468,11 +863,9 @@ fn test_revoke_hook() {
     let schema_uid = BytesN::random(&setup.env);
 
     // Register authority
-    setup.resolver_client.admin_register_authority(
-        &setup.admin,
-        &authority,
-        &SorobanString::from_str(&setup.env, "Revoker"),
-    );
+    setup
+        .resolver_client
+        .admin_register_authority(&setup.admin, &authority, &test_profile(&setup.env, "Revoker"));
 
     // Case 1: Revocation by registered authority
     let attestation1 = create_dummy_attestation(&setup.env, &authority, &schema_uid, None);
@@ -487,131 +880,1357 @@ fn test_revoke_hook() {
 }
 
 // ══════════════════════════════════════════════════════════════════════════════
-// ► Tests Levy Withdrawal
+// ► Tests Authority Lifecycle
 // ══════════════════════════════════════════════════════════════════════════════
-
 #[test]
-fn test_withdraw_levies() {
-    let env = Env::default();
-    env.ledger().set(LedgerInfo {
-        timestamp: 1678886400,
-        protocol_version: 22,
-        sequence_number: 10,
-        network_id: Default::default(),
-        base_reserve: 10,
-        min_temp_entry_ttl: 16 * 60 * 60 * 24,
-        min_persistent_entry_ttl: 30 * 60 * 60 * 24,
-        max_entry_ttl: 365 * 60 * 60 * 24,
-    });
-    let admin = Address::generate(&env);
-    let _attester_auth = Address::generate(&env);
-    let recipient_auth = Address::generate(&env);
-    let levy_amount1 = 5_0000000;
-    let levy_amount2 = 3_0000000;
-    let total_levy = levy_amount1 + levy_amount2;
-    let (token_address, token_client, token_admin_client) = create_token_contract(&env, &admin);
-    let resolver_address = env.register(AuthorityResolverContract, ());
-    let resolver_client = AuthorityResolverContractClient::new(&env, &resolver_address);
-
-    // Define mocks needed later
-    let transfer_withdraw_invoke = soroban_sdk::testutils::MockAuthInvoke {
-        contract: &token_address,
-        fn_name: "transfer",
-        args: (resolver_address.clone(), recipient_auth.clone(), total_levy).into_val(&env),
-        sub_invokes: &[],
-    };
-    let withdraw_invoke = soroban_sdk::testutils::MockAuthInvoke {
-        contract: &resolver_address,
-        fn_name: "withdraw_levies",
-        args: (recipient_auth.clone(),).into_val(&env),
-        sub_invokes: &[transfer_withdraw_invoke.clone()], // Specify sub-invoke
-    };
-
-    // --- Initialize ---
-    env.mock_auths(&[soroban_sdk::testutils::MockAuth {
-        address: &admin,
-        invoke: &soroban_sdk::testutils::MockAuthInvoke {
-            contract: &resolver_address,
-            fn_name: "initialize",
-            args: (admin.clone(), token_address.clone(), create_dummy_token_wasm_hash(&env)).into_val(&env),
-            sub_invokes: &[],
-        },
-    }]);
-    resolver_client.initialize(&admin, &token_address, &create_dummy_token_wasm_hash(&env));
+fn test_suspend_and_reinstate_authority() {
+    let setup = setup_env(true);
+    let authority = Address::generate(&setup.env);
+    let schema_uid = BytesN::random(&setup.env);
+    setup
+        .resolver_client
+        .admin_register_authority(&setup.admin, &authority, &test_profile(&setup.env, "Issuer"));
 
-    // --- Register recipient as authority ---
-    // (Need this so withdraw_levies doesn't fail the is_authority check)
-    let recipient_meta = SorobanString::from_str(&env, "Recipient");
-    let reg_recipient_invoke = soroban_sdk::testutils::MockAuthInvoke {
-        contract: &resolver_address,
-        fn_name: "admin_register_authority",
-        args: (admin.clone(), recipient_auth.clone(), recipient_meta.clone()).into_val(&env),
-        sub_invokes: &[],
-    };
-    env.mock_auths(&[soroban_sdk::testutils::MockAuth {
-        address: &admin,
-        invoke: &reg_recipient_invoke,
-    }]);
-    resolver_client.admin_register_authority(&admin, &recipient_auth, &recipient_meta);
+    register_open_schema(&setup, &schema_uid);
 
-    // --- Directly Set Levy Balance in Storage --- RESTORED
-    env.as_contract(&resolver_address, || {
-        let balance_key = (DataKey::CollectedLevies, recipient_auth.clone()); // Use the correct prefix
-        env.storage().persistent().set(&balance_key, &total_levy);
-    });
-    let withdraw_levies = resolver_client.get_collected_levies(&recipient_auth);
-    assert_eq!(withdraw_levies, total_levy); // RESTORED assertion
+    let reason = SorobanString::from_str(&setup.env, "pending investigation");
+    setup
+        .resolver_client
+        .suspend_authority(&setup.admin, &authority, &reason);
+    assert!(!setup.resolver_client.is_authority(&authority));
 
-    // --- Manually Mint Levy Amount to Contract Address ---
-    let mint_collected_invoke = soroban_sdk::testutils::MockAuthInvoke {
-        contract: &token_address,
-        fn_name: "mint",
-        args: (resolver_address.clone(), total_levy).into_val(&env),
-        sub_invokes: &[],
-    };
-    env.mock_auths(&[soroban_sdk::testutils::MockAuth {
-        address: &admin,
-        invoke: &mint_collected_invoke,
-    }]);
-    token_admin_client.mint(&resolver_address, &total_levy);
-    assert_eq!(token_client.balance(&resolver_address), total_levy); // Verify manual mint
-    assert_eq!(token_client.balance(&recipient_auth), 0); // Assert recipient balance is 0 before withdrawal
+    let attestation = create_dummy_attestation(&setup.env, &authority, &schema_uid, None);
+    let result = setup.resolver_client.try_attest(&attestation);
+    assert!(matches!(result.err().unwrap(), Ok(Error::AuthorityNotActive)));
 
-    // --- Withdraw levies (Mocking top-level with specified sub-invoke) ---
-    env.mock_auths(&[
-        soroban_sdk::testutils::MockAuth {
-            address: &recipient_auth,
-            invoke: &withdraw_invoke,
-        },
-        // No separate mock needed for internal transfer as it's in sub_invokes
-    ]);
-    resolver_client.withdraw_levies(&recipient_auth);
+    setup.env.ledger().with_mut(|li| li.timestamp += 3600);
+    let cleared = SorobanString::from_str(&setup.env, "investigation closed");
+    setup
+        .resolver_client
+        .reinstate_authority(&setup.admin, &authority, &cleared);
+    assert!(setup.resolver_client.is_authority(&authority));
+    assert!(setup.resolver_client.attest(&attestation));
 
-    // --- Verify after withdrawal ---
-    assert_eq!(resolver_client.get_collected_levies(&recipient_auth), 0);
-    assert_eq!(token_client.balance(&recipient_auth), total_levy);
-    assert_eq!(token_client.balance(&resolver_address), 0);
+    let history = setup
+        .resolver_client
+        .get_authority_status_history(&authority, &0, &STATUS_HISTORY_PAGE_SIZE);
+    assert_eq!(history.len(), 2);
+    let suspended = history.get(0).unwrap();
+    assert_eq!(suspended.status, AuthorityStatus::Suspended);
+    assert_eq!(suspended.reason, reason);
+    assert_eq!(suspended.changed_by, setup.admin);
+    let reinstated = history.get(1).unwrap();
+    assert_eq!(reinstated.status, AuthorityStatus::Active);
+    assert_eq!(reinstated.timestamp, suspended.timestamp + 3600);
 }
 
 #[test]
-fn test_withdraw_levies_nothing_to_withdraw() {
+fn test_revoke_authority_is_terminal() {
     let setup = setup_env(true);
     let authority = Address::generate(&setup.env);
-    setup.resolver_client.admin_register_authority(
+    setup
+        .resolver_client
+        .admin_register_authority(&setup.admin, &authority, &test_profile(&setup.env, "Issuer"));
+
+    let reason = SorobanString::from_str(&setup.env, "fraudulent issuance");
+    setup
+        .resolver_client
+        .revoke_authority(&setup.admin, &authority, &reason);
+    assert!(!setup.resolver_client.is_authority(&authority));
+    let data = setup.resolver_client.get_authority_data(&authority).unwrap();
+    assert_eq!(data.status, AuthorityStatus::Revoked);
+
+    let result = setup
+        .resolver_client
+        .try_reinstate_authority(&setup.admin, &authority, &reason);
+    assert!(matches!(result.err().unwrap(), Ok(Error::InvalidStatusTransition)));
+
+    // Re-registration cannot lift a revocation
+    let result = setup.resolver_client.try_admin_register_authority(
+        &setup.admin,
+        &authority,
+        &test_profile(&setup.env, "Issuer"),
+    );
+    assert!(matches!(result.err().unwrap(), Ok(Error::AuthorityNotActive)));
+}
+
+#[test]
+fn test_status_history_is_paged() {
+    let setup = setup_env(true);
+    let authority = Address::generate(&setup.env);
+    setup
+        .resolver_client
+        .admin_register_authority(&setup.admin, &authority, &test_profile(&setup.env, "Issuer"));
+    let reason = SorobanString::from_str(&setup.env, "periodic review");
+    for _ in 0..STATUS_HISTORY_PAGE_SIZE / 2 + 1 {
+        setup
+            .resolver_client
+            .suspend_authority(&setup.admin, &authority, &reason);
+        setup
+            .resolver_client
+            .reinstate_authority(&setup.admin, &authority, &reason);
+    }
+    let count = STATUS_HISTORY_PAGE_SIZE + 2;
+    assert_eq!(setup.resolver_client.get_authority_status_count(&authority), count);
+
+    // A page never exceeds the cap, however large the limit
+    let first = setup
+        .resolver_client
+        .get_authority_status_history(&authority, &0, &u32::MAX);
+    assert_eq!(first.len(), STATUS_HISTORY_PAGE_SIZE);
+    assert_eq!(first.get(0).unwrap().status, AuthorityStatus::Suspended);
+
+    let rest = setup.resolver_client.get_authority_status_history(
+        &authority,
+        &STATUS_HISTORY_PAGE_SIZE,
+        &STATUS_HISTORY_PAGE_SIZE,
+    );
+    assert_eq!(rest.len(), 2);
+    assert_eq!(rest.get(0).unwrap().status, AuthorityStatus::Suspended);
+    assert_eq!(rest.get(1).unwrap().status, AuthorityStatus::Active);
+
+    let past_end = setup
+        .resolver_client
+        .get_authority_status_history(&authority, &count, &1);
+    assert!(past_end.is_empty());
+}
+
+#[test]
+fn test_lifecycle_requires_owner_and_registered_authority() {
+    let setup = setup_env(true);
+    let non_admin = Address::generate(&setup.env);
+    let authority = Address::generate(&setup.env);
+    let reason = SorobanString::from_str(&setup.env, "reason");
+
+    let result = setup
+        .resolver_client
+        .try_suspend_authority(&setup.admin, &authority, &reason);
+    assert!(matches!(result.err().unwrap(), Ok(Error::AuthorityNotFound)));
+
+    setup
+        .resolver_client
+        .admin_register_authority(&setup.admin, &authority, &test_profile(&setup.env, "Issuer"));
+    let result = setup
+        .resolver_client
+        .try_suspend_authority(&non_admin, &authority, &reason);
+    assert!(matches!(result.err().unwrap(), Ok(Error::NotAuthorized)));
+
+    let result = setup
+        .resolver_client
+        .try_reinstate_authority(&setup.admin, &authority, &reason);
+    assert!(matches!(result.err().unwrap(), Ok(Error::InvalidStatusTransition)));
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Tests Registration Validity & Renewal
+// ══════════════════════════════════════════════════════════════════════════════
+const VALIDITY_PERIOD: u64 = 365 * 24 * 60 * 60; // 1 year
+const GRACE_PERIOD: u64 = 30 * 24 * 60 * 60; // 30 days
+
+#[test]
+fn test_registration_expires_after_grace_period() {
+    let setup = setup_env(true);
+    let authority = Address::generate(&setup.env);
+    let schema_uid = BytesN::random(&setup.env);
+    setup
+        .resolver_client
+        .set_validity_period(&setup.admin, &Some(VALIDITY_PERIOD), &GRACE_PERIOD);
+
+    let start = setup.env.ledger().timestamp();
+    setup
+        .resolver_client
+        .admin_register_authority(&setup.admin, &authority, &test_profile(&setup.env, "Issuer"));
+    let data = setup.resolver_client.get_authority_data(&authority).unwrap();
+    assert_eq!(data.expires_at, Some(start + VALIDITY_PERIOD));
+    assert!(!setup
+        .resolver_client
+        .authority_expiring(&authority, &(VALIDITY_PERIOD / 2)));
+    assert!(setup.resolver_client.authority_expiring(&authority, &VALIDITY_PERIOD));
+
+    // Expired but inside the grace period: still active, and reported as expiring
+    setup
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = start + VALIDITY_PERIOD + 1);
+    assert!(setup.resolver_client.is_authority(&authority));
+    assert!(setup.resolver_client.authority_expiring(&authority, &0));
+
+    // Lapsed
+    setup
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = start + VALIDITY_PERIOD + GRACE_PERIOD);
+    assert!(!setup.resolver_client.is_authority(&authority));
+    assert!(!setup.resolver_client.authority_expiring(&authority, &VALIDITY_PERIOD));
+    let attestation = create_dummy_attestation(&setup.env, &authority, &schema_uid, None);
+    let result = setup.resolver_client.try_attest(&attestation);
+    assert!(matches!(result.err().unwrap(), Ok(Error::AuthorityExpired)));
+}
+
+#[test]
+fn test_renew_authority_extends_expiry() {
+    let setup = setup_env(true);
+    let authority = Address::generate(&setup.env);
+    setup.token_admin_client.mint(&authority, &MINT_AMOUNT);
+    setup
+        .resolver_client
+        .set_validity_period(&setup.admin, &Some(VALIDITY_PERIOD), &GRACE_PERIOD);
+
+    let start = setup.env.ledger().timestamp();
+    setup
+        .resolver_client
+        .admin_register_authority(&setup.admin, &authority, &test_profile(&setup.env, "Issuer"));
+
+    // Renewing during the grace period extends from the previous expiry
+    setup
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = start + VALIDITY_PERIOD + 10);
+    let expires_at = setup.resolver_client.renew_authority(&authority, &setup.token_address);
+    assert_eq!(expires_at, start + 2 * VALIDITY_PERIOD);
+    assert_eq!(setup.token_client.balance(&authority), MINT_AMOUNT - REGISTRATION_FEE);
+    let payment = setup.resolver_client.get_payment_record(&authority).unwrap();
+    assert_eq!(payment.expires_at, Some(expires_at));
+    assert_eq!(payment.amount_paid, REGISTRATION_FEE);
+
+    // Renewing after the registration lapsed starts a new period from now
+    let lapsed_at = expires_at + GRACE_PERIOD + 10;
+    setup.env.ledger().with_mut(|li| li.timestamp = lapsed_at);
+    assert!(!setup.resolver_client.is_authority(&authority));
+    let expires_at = setup.resolver_client.renew_authority(&authority, &setup.token_address);
+    assert_eq!(expires_at, lapsed_at + VALIDITY_PERIOD);
+    assert!(setup.resolver_client.is_authority(&authority));
+}
+
+#[test]
+fn test_renew_authority_errors() {
+    let setup = setup_env(true);
+    let authority = Address::generate(&setup.env);
+    setup.token_admin_client.mint(&authority, &MINT_AMOUNT);
+
+    let result = setup
+        .resolver_client
+        .try_renew_authority(&authority, &setup.token_address);
+    assert!(matches!(result.err().unwrap(), Ok(Error::AuthorityNotFound)));
+
+    // Registered while no validity period was configured: permanent
+    setup
+        .resolver_client
+        .admin_register_authority(&setup.admin, &authority, &test_profile(&setup.env, "Issuer"));
+    assert_eq!(
+        setup.resolver_client.get_authority_data(&authority).unwrap().expires_at,
+        None
+    );
+    setup
+        .resolver_client
+        .set_validity_period(&setup.admin, &Some(VALIDITY_PERIOD), &GRACE_PERIOD);
+    let result = setup
+        .resolver_client
+        .try_renew_authority(&authority, &setup.token_address);
+    assert!(matches!(result.err().unwrap(), Ok(Error::RenewalNotRequired)));
+    assert_eq!(setup.token_client.balance(&authority), MINT_AMOUNT);
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Tests Verification Levels
+// ══════════════════════════════════════════════════════════════════════════════
+#[test]
+fn test_attest_enforces_schema_min_level() {
+    let setup = setup_env(true);
+    let authority = Address::generate(&setup.env);
+    let schema_uid = BytesN::random(&setup.env);
+    setup
+        .resolver_client
+        .admin_register_authority(&setup.admin, &authority, &test_profile(&setup.env, "Issuer"));
+    let data = setup.resolver_client.get_authority_data(&authority).unwrap();
+    assert_eq!(data.level, VerificationLevel::Basic);
+    assert_eq!(
+        setup.resolver_client.get_schema_min_level(&schema_uid),
+        VerificationLevel::Basic
+    );
+
+    register_open_schema(&setup, &schema_uid);
+    setup
+        .resolver_client
+        .set_schema_min_level(&setup.admin, &schema_uid, &VerificationLevel::KybVerified);
+    let attestation = create_dummy_attestation(&setup.env, &authority, &schema_uid, None);
+    let result = setup.resolver_client.try_attest(&attestation);
+    assert!(matches!(result.err().unwrap(), Ok(Error::ExceedsVerificationLevel)));
+
+    // A higher tier satisfies every lower requirement
+    setup
+        .resolver_client
+        .set_verification_level(&setup.admin, &authority, &VerificationLevel::Regulated);
+    assert!(setup.resolver_client.attest(&attestation));
+
+    // Re-registration keeps the tier
+    setup
+        .resolver_client
+        .admin_register_authority(&setup.admin, &authority, &test_profile(&setup.env, "Issuer v2"));
+    let data = setup.resolver_client.get_authority_data(&authority).unwrap();
+    assert_eq!(data.level, VerificationLevel::Regulated);
+}
+
+#[test]
+fn test_set_verification_level_errors() {
+    let setup = setup_env(true);
+    let authority = Address::generate(&setup.env);
+    let non_admin = Address::generate(&setup.env);
+
+    let result =
+        setup
+            .resolver_client
+            .try_set_verification_level(&setup.admin, &authority, &VerificationLevel::KybVerified);
+    assert!(matches!(result.err().unwrap(), Ok(Error::VerifierNotFound)));
+
+    setup
+        .resolver_client
+        .admin_register_authority(&setup.admin, &authority, &test_profile(&setup.env, "Issuer"));
+    let result =
+        setup
+            .resolver_client
+            .try_set_verification_level(&non_admin, &authority, &VerificationLevel::KybVerified);
+    assert!(matches!(result.err().unwrap(), Ok(Error::NotAuthorized)));
+
+    setup
+        .resolver_client
+        .suspend_authority(&setup.admin, &authority, &SorobanString::from_str(&setup.env, "review"));
+    let result =
+        setup
+            .resolver_client
+            .try_set_verification_level(&setup.admin, &authority, &VerificationLevel::KybVerified);
+    assert!(matches!(result.err().unwrap(), Ok(Error::VerifierInactive)));
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Tests Levy Withdrawal
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_withdraw_levies() {
+    let env = Env::default();
+    env.ledger().set(LedgerInfo {
+        timestamp: 1678886400,
+        protocol_version: 22,
+        sequence_number: 10,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 16 * 60 * 60 * 24,
+        min_persistent_entry_ttl: 30 * 60 * 60 * 24,
+        max_entry_ttl: 365 * 60 * 60 * 24,
+    });
+    let admin = Address::generate(&env);
+    let protocol = Address::generate(&env);
+    let _attester_auth = Address::generate(&env);
+    let recipient_auth = Address::generate(&env);
+    let levy_amount1 = 5_0000000;
+    let levy_amount2 = 3_0000000;
+    let total_levy = levy_amount1 + levy_amount2;
+    let (token_address, token_client, token_admin_client) = create_token_contract(&env, &admin);
+    let resolver_address = env.register(AuthorityResolverContract, ());
+    let resolver_client = AuthorityResolverContractClient::new(&env, &resolver_address);
+
+    // Define mocks needed later
+    let transfer_withdraw_invoke = soroban_sdk::testutils::MockAuthInvoke {
+        contract: &token_address,
+        fn_name: "transfer",
+        args: (resolver_address.clone(), recipient_auth.clone(), total_levy).into_val(&env),
+        sub_invokes: &[],
+    };
+    let withdraw_invoke = soroban_sdk::testutils::MockAuthInvoke {
+        contract: &resolver_address,
+        fn_name: "withdraw_levies",
+        args: (recipient_auth.clone(), token_address.clone()).into_val(&env),
+        sub_invokes: std::slice::from_ref(&transfer_withdraw_invoke), // Specify sub-invoke
+    };
+
+    // --- Initialize ---
+    env.mock_auths(&[soroban_sdk::testutils::MockAuth {
+        address: &admin,
+        invoke: &soroban_sdk::testutils::MockAuthInvoke {
+            contract: &resolver_address,
+            fn_name: "initialize",
+            args: (
+                admin.clone(),
+                token_address.clone(),
+                create_dummy_token_wasm_hash(&env),
+                protocol.clone(),
+            )
+                .into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    resolver_client.initialize(&admin, &token_address, &create_dummy_token_wasm_hash(&env), &protocol);
+
+    // --- Register recipient as authority ---
+    // (Need this so withdraw_levies doesn't fail the is_authority check)
+    let recipient_meta = test_profile(&env, "Recipient");
+    let reg_recipient_invoke = soroban_sdk::testutils::MockAuthInvoke {
+        contract: &resolver_address,
+        fn_name: "admin_register_authority",
+        args: (admin.clone(), recipient_auth.clone(), recipient_meta.clone()).into_val(&env),
+        sub_invokes: &[],
+    };
+    env.mock_auths(&[soroban_sdk::testutils::MockAuth {
+        address: &admin,
+        invoke: &reg_recipient_invoke,
+    }]);
+    resolver_client.admin_register_authority(&admin, &recipient_auth, &recipient_meta);
+
+    // --- Directly Set Levy Balance in Storage --- RESTORED
+    env.as_contract(&resolver_address, || {
+        let balance_key = (DataKey::CollectedLevies, recipient_auth.clone(), token_address.clone());
+        env.storage().persistent().set(&balance_key, &total_levy);
+    });
+    let withdraw_levies = resolver_client.get_collected_levies(&recipient_auth, &token_address);
+    assert_eq!(withdraw_levies, total_levy); // RESTORED assertion
+
+    // --- Manually Mint Levy Amount to Contract Address ---
+    let mint_collected_invoke = soroban_sdk::testutils::MockAuthInvoke {
+        contract: &token_address,
+        fn_name: "mint",
+        args: (resolver_address.clone(), total_levy).into_val(&env),
+        sub_invokes: &[],
+    };
+    env.mock_auths(&[soroban_sdk::testutils::MockAuth {
+        address: &admin,
+        invoke: &mint_collected_invoke,
+    }]);
+    token_admin_client.mint(&resolver_address, &total_levy);
+    assert_eq!(token_client.balance(&resolver_address), total_levy); // Verify manual mint
+    assert_eq!(token_client.balance(&recipient_auth), 0); // Assert recipient balance is 0 before withdrawal
+
+    // --- Withdraw levies (Mocking top-level with specified sub-invoke) ---
+    env.mock_auths(&[
+        soroban_sdk::testutils::MockAuth {
+            address: &recipient_auth,
+            invoke: &withdraw_invoke,
+        },
+        // No separate mock needed for internal transfer as it's in sub_invokes
+    ]);
+    resolver_client.withdraw_levies(&recipient_auth, &token_address);
+
+    // --- Verify after withdrawal ---
+    assert_eq!(resolver_client.get_collected_levies(&recipient_auth, &token_address), 0);
+    assert_eq!(token_client.balance(&recipient_auth), total_levy);
+    assert_eq!(token_client.balance(&resolver_address), 0);
+}
+
+#[test]
+fn test_withdraw_levies_nothing_to_withdraw() {
+    let setup = setup_env(true);
+    let authority = Address::generate(&setup.env);
+    setup
+        .resolver_client
+        .admin_register_authority(&setup.admin, &authority, &test_profile(&setup.env, "Recipient"));
+    let result = setup
+        .resolver_client
+        .try_withdraw_levies(&authority, &setup.token_address);
+    assert!(matches!(result.err().unwrap(), Ok(Error::NothingToWithdraw)));
+}
+
+#[test]
+fn test_withdraw_levies_not_authority() {
+    let setup = setup_env(true);
+    let non_authority = Address::generate(&setup.env);
+    let result = setup
+        .resolver_client
+        .try_withdraw_levies(&non_authority, &setup.token_address);
+    assert!(matches!(result.err().unwrap(), Ok(Error::NotAuthorized)));
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Tests Levy Balances
+// ══════════════════════════════════════════════════════════════════════════════
+
+// Levy balances of an authority holding `amount` in the primary token only
+fn levies_in_primary_token(setup: &TestSetup, amount: i128) -> Map<Address, i128> {
+    soroban_sdk::map![&setup.env, (setup.token_address.clone(), amount)]
+}
+
+// Registers an authority that has collected `levies` from a levied attestation
+fn setup_authority_levies(setup: &TestSetup, levies: i128) -> Address {
+    let attester = Address::generate(&setup.env);
+    let authority = Address::generate(&setup.env);
+    for addr in [&attester, &authority] {
+        setup
+            .resolver_client
+            .admin_register_authority(&setup.admin, addr, &test_profile(&setup.env, "Recipient"));
+    }
+    let schema_uid = BytesN::random(&setup.env);
+    let rules = SchemaRules {
+        levy_amount: Some(levies),
+        levy_recipient: Some(authority.clone()),
+        ..open_schema_rules(&setup.env)
+    };
+    setup
+        .resolver_client
+        .admin_register_schema(&setup.admin, &schema_uid, &rules);
+    setup.token_admin_client.mint(&attester, &levies);
+    let attestation = create_dummy_attestation(&setup.env, &attester, &schema_uid, None);
+    setup.resolver_client.attest(&attestation);
+    authority
+}
+
+#[test]
+fn test_withdraw_levies_cannot_be_repeated() {
+    let setup = setup_env(true);
+    let authority = setup_authority_levies(&setup, DEFAULT_LEVY);
+    assert_eq!(
+        setup.resolver_client.get_balances(&authority),
+        AuthorityBalances {
+            levies: levies_in_primary_token(&setup, DEFAULT_LEVY)
+        }
+    );
+
+    setup.resolver_client.withdraw_levies(&authority, &setup.token_address);
+    assert!(has_event(&setup.env, LEVY_WITHDRAWN));
+    assert_eq!(setup.token_client.balance(&authority), DEFAULT_LEVY);
+    assert_eq!(
+        setup.resolver_client.get_balances(&authority),
+        AuthorityBalances {
+            levies: Map::new(&setup.env)
+        }
+    );
+
+    // Regression: the same levies cannot be withdrawn twice
+    let result = setup
+        .resolver_client
+        .try_withdraw_levies(&authority, &setup.token_address);
+    assert!(matches!(result.err().unwrap(), Ok(Error::NothingToWithdraw)));
+    assert_eq!(setup.token_client.balance(&authority), DEFAULT_LEVY);
+    assert_eq!(setup.token_client.balance(&setup.resolver_address), 0);
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Tests Ownership Transfer
+// ══════════════════════════════════════════════════════════════════════════════
+
+// Returns true if an event with `topic` was published by the last invocation
+fn has_event(env: &Env, topic: soroban_sdk::Symbol) -> bool {
+    env.events().all().iter().any(|(_, topics, _)| {
+        topics
+            .get(0)
+            .and_then(|v| soroban_sdk::Symbol::try_from_val(env, &v).ok())
+            == Some(topic.clone())
+    })
+}
+
+#[test]
+fn test_two_step_ownership_transfer() {
+    let setup = setup_env(true);
+    let new_owner = Address::generate(&setup.env);
+    let live_until = setup.env.ledger().sequence() + 100;
+
+    setup
+        .resolver_client
+        .transfer_ownership(&setup.admin, &new_owner, &live_until);
+    assert!(has_event(&setup.env, OWNERSHIP_TRANSFER_STARTED));
+
+    // Nothing changes until the new owner accepts
+    assert_eq!(setup.resolver_client.get_owner(), setup.admin);
+    assert_eq!(setup.resolver_client.get_pending_owner(), Some(new_owner.clone()));
+
+    let impostor = Address::generate(&setup.env);
+    let result = setup.resolver_client.try_accept_ownership(&impostor);
+    assert!(matches!(result.err().unwrap(), Ok(Error::NotAuthorized)));
+
+    setup.resolver_client.accept_ownership(&new_owner);
+    assert!(has_event(&setup.env, OWNERSHIP_TRANSFERRED));
+    assert_eq!(setup.resolver_client.get_owner(), new_owner);
+    assert_eq!(setup.resolver_client.get_pending_owner(), None);
+
+    // The previous owner lost its privileges
+    let result = setup.resolver_client.try_admin_register_authority(
+        &setup.admin,
+        &Address::generate(&setup.env),
+        &test_profile(&setup.env, "Meta"),
+    );
+    assert!(matches!(result.err().unwrap(), Ok(Error::NotAuthorized)));
+}
+
+#[test]
+fn test_ownership_offer_expires() {
+    let setup = setup_env(true);
+    let new_owner = Address::generate(&setup.env);
+    let current = setup.env.ledger().sequence();
+
+    let result = setup
+        .resolver_client
+        .try_transfer_ownership(&setup.admin, &new_owner, &(current - 1));
+    assert!(matches!(result.err().unwrap(), Ok(Error::InvalidLiveUntilLedger)));
+
+    setup
+        .resolver_client
+        .transfer_ownership(&setup.admin, &new_owner, &(current + 10));
+    setup.env.ledger().with_mut(|li| li.sequence_number = current + 11);
+
+    assert_eq!(setup.resolver_client.get_pending_owner(), None);
+    let result = setup.resolver_client.try_accept_ownership(&new_owner);
+    assert!(matches!(result.err().unwrap(), Ok(Error::OwnershipTransferExpired)));
+    assert_eq!(setup.resolver_client.get_owner(), setup.admin);
+}
+
+#[test]
+fn test_cancel_ownership_transfer() {
+    let setup = setup_env(true);
+    let new_owner = Address::generate(&setup.env);
+    let live_until = setup.env.ledger().sequence() + 100;
+
+    let result = setup.resolver_client.try_cancel_ownership_transfer(&setup.admin);
+    assert!(matches!(result.err().unwrap(), Ok(Error::NoPendingOwnershipTransfer)));
+
+    setup
+        .resolver_client
+        .transfer_ownership(&setup.admin, &new_owner, &live_until);
+    let result = setup.resolver_client.try_cancel_ownership_transfer(&new_owner);
+    assert!(matches!(result.err().unwrap(), Ok(Error::NotAuthorized)));
+
+    setup.resolver_client.cancel_ownership_transfer(&setup.admin);
+    assert!(has_event(&setup.env, OWNERSHIP_TRANSFER_CANCELLED));
+    assert_eq!(setup.resolver_client.get_pending_owner(), None);
+
+    let result = setup.resolver_client.try_accept_ownership(&new_owner);
+    assert!(matches!(result.err().unwrap(), Ok(Error::NoPendingOwnershipTransfer)));
+    assert_eq!(setup.resolver_client.get_owner(), setup.admin);
+}
+
+#[test]
+fn test_renounce_ownership_closes_owner_paths() {
+    let setup = setup_env(true);
+    let new_owner = Address::generate(&setup.env);
+    let live_until = setup.env.ledger().sequence() + 100;
+    setup
+        .resolver_client
+        .transfer_ownership(&setup.admin, &new_owner, &live_until);
+
+    setup.resolver_client.renounce_ownership(&setup.admin);
+    assert!(has_event(&setup.env, OWNERSHIP_RENOUNCED));
+    assert!(setup.resolver_client.is_ownership_renounced());
+    assert!(!setup.resolver_client.is_owner(&setup.admin));
+    let result = setup.resolver_client.try_get_owner();
+    assert!(matches!(result.err().unwrap(), Ok(Error::OwnershipRenounced)));
+
+    // The pending offer was discarded
+    assert_eq!(setup.resolver_client.get_pending_owner(), None);
+    let result = setup.resolver_client.try_accept_ownership(&new_owner);
+    assert!(matches!(result.err().unwrap(), Ok(Error::NoPendingOwnershipTransfer)));
+
+    let result = setup.resolver_client.try_admin_register_authority(
         &setup.admin,
+        &Address::generate(&setup.env),
+        &test_profile(&setup.env, "Meta"),
+    );
+    assert!(matches!(result.err().unwrap(), Ok(Error::OwnershipRenounced)));
+    let result = setup
+        .resolver_client
+        .try_admin_withdraw_fees(&setup.admin, &setup.token_address, &1);
+    assert!(matches!(result.err().unwrap(), Ok(Error::OwnershipRenounced)));
+    let result = setup.resolver_client.try_renounce_ownership(&setup.admin);
+    assert!(matches!(result.err().unwrap(), Ok(Error::OwnershipRenounced)));
+}
+
+#[test]
+fn test_migrate_legacy_renounced_owner() {
+    let setup = setup_env(true);
+    assert!(!setup.resolver_client.migrate_renounced_ownership());
+
+    // Simulate a contract renounced by an earlier version
+    let legacy_owner = Address::from_string(&SorobanString::from_str(
+        &setup.env,
+        "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+    ));
+    setup.env.as_contract(&setup.resolver_address, || {
+        setup.env.storage().instance().set(&DataKey::Admin, &legacy_owner);
+    });
+    assert_eq!(setup.resolver_client.get_owner(), legacy_owner);
+
+    assert!(setup.resolver_client.migrate_renounced_ownership());
+    assert!(has_event(&setup.env, OWNERSHIP_RENOUNCED));
+    assert!(setup.resolver_client.is_ownership_renounced());
+    let result = setup.resolver_client.try_get_owner();
+    assert!(matches!(result.err().unwrap(), Ok(Error::OwnershipRenounced)));
+
+    // Idempotent
+    assert!(!setup.resolver_client.migrate_renounced_ownership());
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Tests Authority Profiles
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_update_authority_profile() {
+    let setup = setup_env(true);
+    let authority = Address::generate(&setup.env);
+    let registrar = Address::generate(&setup.env);
+    setup
+        .resolver_client
+        .grant_role(&setup.admin, &Role::Registrar, &registrar);
+    setup
+        .resolver_client
+        .admin_register_authority(&setup.admin, &authority, &test_profile(&setup.env, "Acme"));
+    assert_eq!(
+        setup
+            .resolver_client
+            .get_authority_data(&authority)
+            .unwrap()
+            .profile_version,
+        1
+    );
+
+    // The authority maintains its own profile
+    let profile = AuthorityProfile {
+        name: SorobanString::from_str(&setup.env, "Acme Ltd"),
+        website: Some(SorobanString::from_str(&setup.env, "acme.example")),
+        jurisdiction: Some(SorobanString::from_str(&setup.env, "GB")),
+        registry_id: Some(SorobanString::from_str(&setup.env, "01234567")),
+        contact: Some(SorobanString::from_str(&setup.env, "mailto:trust@acme.example")),
+        documents_hash: Some(BytesN::from_array(&setup.env, &[7u8; 32])),
+    };
+    let version = setup
+        .resolver_client
+        .update_authority_profile(&authority, &authority, &profile);
+    assert_eq!(version, 2);
+    assert!(has_event(&setup.env, AUTHORITY_PROFILE_UPDATED));
+    let data = setup.resolver_client.get_authority_data(&authority).unwrap();
+    assert_eq!(data.profile, profile);
+    assert_eq!(data.profile_version, 2);
+
+    // So can a registrar, but nobody else
+    let version =
+        setup
+            .resolver_client
+            .update_authority_profile(&registrar, &authority, &test_profile(&setup.env, "Acme Group"));
+    assert_eq!(version, 3);
+    let result = setup.resolver_client.try_update_authority_profile(
+        &Address::generate(&setup.env),
         &authority,
-        &SorobanString::from_str(&setup.env, "Recipient"),
+        &test_profile(&setup.env, "Hijacked"),
     );
-    let result = setup.resolver_client.try_withdraw_levies(&authority);
-    assert!(matches!(result.err().unwrap(), Ok(Error::NothingToWithdraw)));
+    assert!(matches!(result.err().unwrap(), Ok(Error::NotAuthorized)));
+
+    // Profiles need a name and bounded fields
+    let result =
+        setup
+            .resolver_client
+            .try_update_authority_profile(&authority, &authority, &test_profile(&setup.env, ""));
+    assert!(matches!(result.err().unwrap(), Ok(Error::InvalidAuthorityProfile)));
+    let long_name = "x".repeat(257);
+    let result = setup.resolver_client.try_update_authority_profile(
+        &authority,
+        &authority,
+        &test_profile(&setup.env, &long_name),
+    );
+    assert!(matches!(result.err().unwrap(), Ok(Error::InvalidAuthorityProfile)));
+
+    // Only registered authorities have a profile
+    let stranger = Address::generate(&setup.env);
+    let result =
+        setup
+            .resolver_client
+            .try_update_authority_profile(&stranger, &stranger, &test_profile(&setup.env, "Nobody"));
+    assert!(matches!(result.err().unwrap(), Ok(Error::VerifierNotFound)));
 }
 
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Tests Roles
+// ══════════════════════════════════════════════════════════════════════════════
+
 #[test]
-fn test_withdraw_levies_not_authority() {
+fn test_registrar_role_grants_and_revokes_registration_rights() {
     let setup = setup_env(true);
-    let non_authority = Address::generate(&setup.env);
-    let result = setup.resolver_client.try_withdraw_levies(&non_authority);
+    let registrar = Address::generate(&setup.env);
+    let authority = Address::generate(&setup.env);
+    let metadata = test_profile(&setup.env, "Meta");
+    let reason = SorobanString::from_str(&setup.env, "Audit");
+
+    setup
+        .resolver_client
+        .grant_role(&setup.admin, &Role::Registrar, &registrar);
+    assert!(has_event(&setup.env, ROLE_GRANTED));
+    assert!(setup.resolver_client.has_role(&Role::Registrar, &registrar));
+
+    // A registrar registers and suspends authorities...
+    setup
+        .resolver_client
+        .admin_register_authority(&registrar, &authority, &metadata);
+    setup.resolver_client.suspend_authority(&registrar, &authority, &reason);
+    assert_eq!(
+        setup.resolver_client.get_authority_data(&authority).unwrap().status,
+        AuthorityStatus::Suspended
+    );
+
+    // ...but cannot change platform configuration
+    let result = setup
+        .resolver_client
+        .try_set_registration_fee(&registrar, &1, &setup.token_address);
+    assert!(matches!(result.err().unwrap(), Ok(Error::NotAuthorized)));
+
+    setup
+        .resolver_client
+        .revoke_role(&setup.admin, &Role::Registrar, &registrar);
+    assert!(has_event(&setup.env, ROLE_REVOKED));
+    assert!(!setup.resolver_client.has_role(&Role::Registrar, &registrar));
+
+    let result =
+        setup
+            .resolver_client
+            .try_admin_register_authority(&registrar, &Address::generate(&setup.env), &metadata);
+    assert!(matches!(result.err().unwrap(), Ok(Error::NotAuthorized)));
+}
+
+#[test]
+fn test_treasurer_role_withdraws_fees() {
+    let setup = setup_env(true);
+    let treasurer = Address::generate(&setup.env);
+    let payer = Address::generate(&setup.env);
+    setup.token_admin_client.mint(&payer, &REGISTRATION_FEE);
+    setup.resolver_client.pay_verification_fee(
+        &payer,
+        &SorobanString::from_str(&setup.env, "org-1"),
+        &setup.token_address,
+    );
+
+    let result = setup
+        .resolver_client
+        .try_admin_withdraw_fees(&treasurer, &setup.token_address, &REGISTRATION_FEE);
+    assert!(matches!(result.err().unwrap(), Ok(Error::NotAuthorized)));
+
+    setup
+        .resolver_client
+        .grant_role(&setup.admin, &Role::Treasurer, &treasurer);
+    setup
+        .resolver_client
+        .admin_withdraw_fees(&treasurer, &setup.token_address, &REGISTRATION_FEE);
+    assert_eq!(setup.token_client.balance(&treasurer), REGISTRATION_FEE);
+
+    // Treasurers cannot register authorities
+    let result = setup.resolver_client.try_admin_register_authority(
+        &treasurer,
+        &Address::generate(&setup.env),
+        &test_profile(&setup.env, "Meta"),
+    );
+    assert!(matches!(result.err().unwrap(), Ok(Error::NotAuthorized)));
+}
+
+#[test]
+fn test_super_admin_manages_roles_below_it() {
+    let setup = setup_env(true);
+    let super_admin = Address::generate(&setup.env);
+    let pauser = Address::generate(&setup.env);
+
+    setup
+        .resolver_client
+        .grant_role(&setup.admin, &Role::SuperAdmin, &super_admin);
+    setup.resolver_client.grant_role(&super_admin, &Role::Pauser, &pauser);
+    assert!(setup.resolver_client.has_role(&Role::Pauser, &pauser));
+
+    // Super-admins act with every role but only the owner manages super-admins
+    setup
+        .resolver_client
+        .set_registration_fee(&super_admin, &1, &setup.token_address);
+    let result = setup
+        .resolver_client
+        .try_grant_role(&super_admin, &Role::SuperAdmin, &Address::generate(&setup.env));
+    assert!(matches!(result.err().unwrap(), Ok(Error::NotAuthorized)));
+
+    // Role holders cannot grant roles
+    let result = setup
+        .resolver_client
+        .try_grant_role(&pauser, &Role::Registrar, &Address::generate(&setup.env));
+    assert!(matches!(result.err().unwrap(), Ok(Error::NotAuthorized)));
+
+    let result = setup
+        .resolver_client
+        .try_grant_role(&super_admin, &Role::Pauser, &pauser);
+    assert!(matches!(result.err().unwrap(), Ok(Error::RoleAlreadyGranted)));
+}
+
+#[test]
+fn test_renounce_role() {
+    let setup = setup_env(true);
+    let pauser = Address::generate(&setup.env);
+
+    let result = setup.resolver_client.try_renounce_role(&pauser, &Role::Pauser);
+    assert!(matches!(result.err().unwrap(), Ok(Error::RoleNotGranted)));
+
+    setup.resolver_client.grant_role(&setup.admin, &Role::Pauser, &pauser);
+    setup.resolver_client.renounce_role(&pauser, &Role::Pauser);
+    assert!(has_event(&setup.env, ROLE_REVOKED));
+    assert!(!setup.resolver_client.has_role(&Role::Pauser, &pauser));
+
+    let result = setup
+        .resolver_client
+        .try_revoke_role(&setup.admin, &Role::Pauser, &pauser);
+    assert!(matches!(result.err().unwrap(), Ok(Error::RoleNotGranted)));
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Tests Pause
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_pause_blocks_attest_and_withdrawals() {
+    let setup = setup_env(true);
+    let authority = setup_authority_levies(&setup, 20);
+    let schema_uid = BytesN::random(&setup.env);
+    register_open_schema(&setup, &schema_uid);
+
+    setup.resolver_client.pause(&setup.admin);
+    assert!(has_event(&setup.env, CONTRACT_PAUSED));
+    assert!(setup.resolver_client.is_paused());
+
+    let attestation = create_dummy_attestation(&setup.env, &authority, &schema_uid, None);
+    let result = setup.resolver_client.try_attest(&attestation);
+    assert!(matches!(result.err().unwrap(), Ok(Error::ContractPaused)));
+    let result = setup
+        .resolver_client
+        .try_withdraw_levies(&authority, &setup.token_address);
+    assert!(matches!(result.err().unwrap(), Ok(Error::ContractPaused)));
+
+    // Getters and revocation keep working
+    assert!(setup.resolver_client.is_authority(&authority));
+    assert_eq!(
+        setup.resolver_client.get_balances(&authority),
+        AuthorityBalances {
+            levies: levies_in_primary_token(&setup, 20)
+        }
+    );
+    assert!(setup.resolver_client.revoke(&attestation));
+
+    let result = setup.resolver_client.try_pause(&setup.admin);
+    assert!(matches!(result.err().unwrap(), Ok(Error::ContractPaused)));
+
+    setup.resolver_client.unpause(&setup.admin);
+    assert!(has_event(&setup.env, CONTRACT_UNPAUSED));
+    assert!(setup.resolver_client.attest(&attestation));
+    setup.resolver_client.withdraw_levies(&authority, &setup.token_address);
+
+    let result = setup.resolver_client.try_unpause(&setup.admin);
+    assert!(matches!(result.err().unwrap(), Ok(Error::ContractNotPaused)));
+}
+
+#[test]
+fn test_pause_requires_pauser_role() {
+    let setup = setup_env(true);
+    let pauser = Address::generate(&setup.env);
+
+    let result = setup.resolver_client.try_pause(&pauser);
+    assert!(matches!(result.err().unwrap(), Ok(Error::NotAuthorized)));
+
+    setup.resolver_client.grant_role(&setup.admin, &Role::Pauser, &pauser);
+    setup.resolver_client.pause(&pauser);
+    setup.resolver_client.unpause(&pauser);
+    assert!(!setup.resolver_client.is_paused());
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Tests Upgrade & Migration
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_upgrade_requires_owner() {
+    let setup = setup_env(true);
+    let wasm_hash = BytesN::random(&setup.env);
+
+    let registrar = Address::generate(&setup.env);
+    setup
+        .resolver_client
+        .grant_role(&setup.admin, &Role::SuperAdmin, &registrar);
+    let result = setup.resolver_client.try_upgrade(&registrar, &wasm_hash);
+    assert!(matches!(result.err().unwrap(), Ok(Error::NotAuthorized)));
+
+    setup.resolver_client.renounce_ownership(&setup.admin);
+    let result = setup.resolver_client.try_upgrade(&setup.admin, &wasm_hash);
+    assert!(matches!(result.err().unwrap(), Ok(Error::OwnershipRenounced)));
+}
+
+#[test]
+fn test_migrate_runs_once_per_version() {
+    let setup = setup_env(true);
+
+    // Fresh deployments start at the current version
+    assert_eq!(setup.resolver_client.get_version(), 1);
+    let result = setup.resolver_client.try_migrate(&setup.admin);
+    assert!(matches!(result.err().unwrap(), Ok(Error::AlreadyMigrated)));
+
+    // Simulate a deployment that predates versioning
+    setup.env.as_contract(&setup.resolver_address, || {
+        setup.env.storage().instance().remove(&DataKey::ContractVersion);
+    });
+    assert_eq!(setup.resolver_client.get_version(), 0);
+
+    let result = setup.resolver_client.try_migrate(&Address::generate(&setup.env));
+    assert!(matches!(result.err().unwrap(), Ok(Error::NotAuthorized)));
+
+    assert_eq!(setup.resolver_client.migrate(&setup.admin), 1);
+    assert!(has_event(&setup.env, CONTRACT_MIGRATED));
+    assert_eq!(setup.resolver_client.get_version(), 1);
+
+    let result = setup.resolver_client.try_migrate(&setup.admin);
+    assert!(matches!(result.err().unwrap(), Ok(Error::AlreadyMigrated)));
+}
+
+// Writes entries the way older deployments did, under the legacy prefixes
+fn write_legacy_entries(setup: &TestSetup, authority: &Address, levy: i128) {
+    let legacy = LegacyAuthorityData {
+        address: authority.clone(),
+        metadata: SorobanString::from_str(&setup.env, "Legacy Org"),
+        registration_time: 1_600_000_000,
+        ref_id: SorobanString::from_str(&setup.env, "legacy-ref"),
+    };
+    setup.env.as_contract(&setup.resolver_address, || {
+        let storage = setup.env.storage().persistent();
+        storage.set(&(DataKey::RegAuthPrefix, authority.clone()), &legacy);
+        storage.set(&(DataKey::CollLevyPrefix, authority.clone()), &levy);
+    });
+}
+
+#[test]
+fn test_migrate_legacy_entries_moves_authorities_and_levies() {
+    let setup = setup_env(true);
+    let legacy_authority = Address::generate(&setup.env);
+    let untouched = Address::generate(&setup.env);
+    write_legacy_entries(&setup, &legacy_authority, 7);
+
+    // Invisible to the current layout until migrated
+    assert!(!setup.resolver_client.is_authority(&legacy_authority));
+    assert_eq!(
+        setup
+            .resolver_client
+            .get_collected_levies(&legacy_authority, &setup.token_address),
+        0
+    );
+
+    let batch = soroban_sdk::vec![&setup.env, legacy_authority.clone(), untouched.clone()];
+    assert_eq!(setup.resolver_client.migrate_legacy_entries(&setup.admin, &batch), 2);
+    assert!(has_event(&setup.env, LEGACY_MIGRATED));
+
+    let data = setup.resolver_client.get_authority_data(&legacy_authority).unwrap();
+    assert_eq!(data.ref_id, SorobanString::from_str(&setup.env, "legacy-ref"));
+    assert_eq!(data.registration_time, 1_600_000_000);
+    assert_eq!(data.status, AuthorityStatus::Active);
+    assert_eq!(data.expires_at, None);
+    assert!(setup.resolver_client.is_authority(&legacy_authority));
+    assert_eq!(
+        setup
+            .resolver_client
+            .get_collected_levies(&legacy_authority, &setup.token_address),
+        7
+    );
+    assert!(!setup.resolver_client.is_authority(&untouched));
+
+    // Running the same batch again moves nothing
+    assert_eq!(setup.resolver_client.migrate_legacy_entries(&setup.admin, &batch), 0);
+    assert_eq!(
+        setup
+            .resolver_client
+            .get_collected_levies(&legacy_authority, &setup.token_address),
+        7
+    );
+}
+
+#[test]
+fn test_migrate_legacy_entries_moves_untokened_levies_to_primary_token() {
+    let setup = setup_env(true);
+    let authority = setup_authority_levies(&setup, 5);
+    // Balances were once kept per authority, without a token
+    setup.env.as_contract(&setup.resolver_address, || {
+        setup
+            .env
+            .storage()
+            .persistent()
+            .set(&(DataKey::CollectedLevies, authority.clone()), &4i128);
+    });
+
+    let batch = soroban_sdk::vec![&setup.env, authority.clone()];
+    assert_eq!(setup.resolver_client.migrate_legacy_entries(&setup.admin, &batch), 1);
+    assert_eq!(
+        setup.resolver_client.get_balances(&authority).levies,
+        levies_in_primary_token(&setup, 9)
+    );
+    assert_eq!(setup.resolver_client.migrate_legacy_entries(&setup.admin, &batch), 0);
+}
+
+#[test]
+fn test_migrate_legacy_entries_upgrades_payment_records() {
+    let setup = setup_env(true);
+    let payer = Address::generate(&setup.env);
+    // The four-field layout of the original deployment
+    let legacy = LegacyPaymentRecord {
+        recipient: payer.clone(),
+        timestamp: 1_600_000_000,
+        ref_id: SorobanString::from_str(&setup.env, "legacy-pay"),
+        amount_paid: REGISTRATION_FEE,
+    };
+    setup.env.as_contract(&setup.resolver_address, || {
+        let storage = setup.env.storage().persistent();
+        storage.set(&(DataKey::PaymentRecord, payer.clone()), &legacy);
+    });
+
+    let batch = soroban_sdk::vec![&setup.env, payer.clone()];
+    assert_eq!(setup.resolver_client.migrate_legacy_entries(&setup.admin, &batch), 1);
+
+    // Legacy payments granted eligibility as soon as they were made
+    let record = setup.resolver_client.get_payment_record(&payer).unwrap();
+    assert_eq!(record.status, PaymentStatus::Approved);
+    assert_eq!(record.token, setup.token_address);
+    assert_eq!(record.ref_id, legacy.ref_id);
+    assert!(setup.resolver_client.has_confirmed_payment(&payer));
+    assert_eq!(setup.resolver_client.get_payment_count(&payer), 1);
+    assert_eq!(setup.resolver_client.get_total_paid(&payer), REGISTRATION_FEE);
+    assert_eq!(record.expires_at, None);
+
+    // Current records are left alone
+    assert_eq!(setup.resolver_client.migrate_legacy_entries(&setup.admin, &batch), 0);
+    assert_eq!(setup.resolver_client.get_payment_count(&payer), 1);
+}
+
+#[test]
+fn test_migrate_legacy_entries_keeps_current_registration() {
+    let setup = setup_env(true);
+    let authority = setup_authority_levies(&setup, 5);
+    let reason = SorobanString::from_str(&setup.env, "Audit");
+    setup
+        .resolver_client
+        .suspend_authority(&setup.admin, &authority, &reason);
+    write_legacy_entries(&setup, &authority, 3);
+
+    let batch = soroban_sdk::vec![&setup.env, authority.clone()];
+    assert_eq!(setup.resolver_client.migrate_legacy_entries(&setup.admin, &batch), 1);
+
+    // The suspension is not lifted; only the levy balance is merged
+    assert_eq!(
+        setup.resolver_client.get_authority_data(&authority).unwrap().status,
+        AuthorityStatus::Suspended
+    );
+    assert_eq!(
+        setup
+            .resolver_client
+            .get_collected_levies(&authority, &setup.token_address),
+        8
+    );
+    setup.env.as_contract(&setup.resolver_address, || {
+        assert!(!setup
+            .env
+            .storage()
+            .persistent()
+            .has(&(DataKey::RegAuthPrefix, authority.clone())));
+    });
+}
+
+#[test]
+fn test_migrate_legacy_entries_requires_owner_and_bounded_batch() {
+    let setup = setup_env(true);
+    let batch = soroban_sdk::vec![&setup.env, Address::generate(&setup.env)];
+
+    let result = setup
+        .resolver_client
+        .try_migrate_legacy_entries(&Address::generate(&setup.env), &batch);
     assert!(matches!(result.err().unwrap(), Ok(Error::NotAuthorized)));
+
+    let mut too_many = soroban_sdk::Vec::new(&setup.env);
+    for _ in 0..51 {
+        too_many.push_back(Address::generate(&setup.env));
+    }
+    let result = setup
+        .resolver_client
+        .try_migrate_legacy_entries(&setup.admin, &too_many);
+    assert!(matches!(result.err().unwrap(), Ok(Error::MigrationBatchTooLarge)));
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// ► Tests Authority Registry
+// ══════════════════════════════════════════════════════════════════════════════
+
+fn register_authorities(setup: &TestSetup, count: u32) -> std::vec::Vec<Address> {
+    let metadata = test_profile(&setup.env, "Registry Org");
+    (0..count)
+        .map(|_| {
+            let authority = Address::generate(&setup.env);
+            setup
+                .resolver_client
+                .admin_register_authority(&setup.admin, &authority, &metadata);
+            authority
+        })
+        .collect()
+}
+
+#[test]
+fn test_registry_pages_across_index_chunks() {
+    let setup = setup_env(true);
+    let authorities = register_authorities(&setup, REGISTRY_PAGE_SIZE + 20);
+    assert_eq!(setup.resolver_client.authority_count(), REGISTRY_PAGE_SIZE + 20);
+
+    // Re-registering does not duplicate an entry
+    setup
+        .resolver_client
+        .admin_register_authority(&setup.admin, &authorities[0], &test_profile(&setup.env, "Renamed"));
+    assert_eq!(setup.resolver_client.authority_count(), REGISTRY_PAGE_SIZE + 20);
+
+    // A page spanning two chunks keeps registration order
+    let page = setup.resolver_client.list_authorities(&(REGISTRY_PAGE_SIZE - 5), &10);
+    assert_eq!(page.len(), 10);
+    for (i, address) in page.iter().enumerate() {
+        assert_eq!(address, authorities[(REGISTRY_PAGE_SIZE - 5) as usize + i]);
+    }
+
+    // Limits are capped and reads past the end are empty
+    assert_eq!(
+        setup.resolver_client.list_authorities(&0, &u32::MAX).len(),
+        REGISTRY_PAGE_SIZE
+    );
+    assert_eq!(
+        setup.resolver_client.list_authorities(&REGISTRY_PAGE_SIZE, &50).len(),
+        20
+    );
+    assert_eq!(setup.resolver_client.list_authorities(&u32::MAX, &10).len(), 0);
+
+    let by_ref = setup.resolver_client.get_authorities_by_ref_id(
+        &SorobanString::from_str(&setup.env, "admin_registered"),
+        &0,
+        &5,
+    );
+    assert_eq!(by_ref.len(), 5);
+    let unknown_ref = SorobanString::from_str(&setup.env, "unknown");
+    assert_eq!(
+        setup
+            .resolver_client
+            .get_authorities_by_ref_id(&unknown_ref, &0, &5)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_registry_drops_revoked_authorities() {
+    let setup = setup_env(true);
+    let authorities = register_authorities(&setup, 3);
+    let reason = SorobanString::from_str(&setup.env, "Fraud");
+
+    // Suspended authorities stay listed
+    setup
+        .resolver_client
+        .suspend_authority(&setup.admin, &authorities[1], &reason);
+    assert_eq!(setup.resolver_client.authority_count(), 3);
+
+    setup
+        .resolver_client
+        .revoke_authority(&setup.admin, &authorities[0], &reason);
+    assert_eq!(setup.resolver_client.authority_count(), 2);
+
+    // The last authority takes the revoked one's position
+    let listed = setup.resolver_client.list_authorities(&0, &10);
+    assert_eq!(
+        listed,
+        soroban_sdk::vec![&setup.env, authorities[2].clone(), authorities[1].clone()]
+    );
+    let by_ref = setup.resolver_client.get_authorities_by_ref_id(
+        &SorobanString::from_str(&setup.env, "admin_registered"),
+        &0,
+        &10,
+    );
+    assert_eq!(by_ref, listed);
+}
+
+#[test]
+fn test_migrate_legacy_entries_indexes_authorities() {
+    let setup = setup_env(true);
+    let legacy_authority = Address::generate(&setup.env);
+    write_legacy_entries(&setup, &legacy_authority, 0);
+
+    // An authority stored by the original deployment: its current key, but the
+    // four-field layout without index, profile, status, expiry or level
+    let unindexed = Address::generate(&setup.env);
+    let data = LegacyAuthorityData {
+        address: unindexed.clone(),
+        metadata: SorobanString::from_str(&setup.env, "Early Org"),
+        registration_time: 1_600_000_000,
+        ref_id: SorobanString::from_str(&setup.env, "legacy-ref"),
+    };
+    setup.env.as_contract(&setup.resolver_address, || {
+        setup
+            .env
+            .storage()
+            .persistent()
+            .set(&(DataKey::Authority, unindexed.clone()), &data);
+    });
+    assert_eq!(setup.resolver_client.authority_count(), 0);
+
+    let batch = soroban_sdk::vec![&setup.env, legacy_authority.clone(), unindexed.clone()];
+    setup.resolver_client.migrate_legacy_entries(&setup.admin, &batch);
+    assert_eq!(setup.resolver_client.authority_count(), 2);
+    let by_ref =
+        setup
+            .resolver_client
+            .get_authorities_by_ref_id(&SorobanString::from_str(&setup.env, "legacy-ref"), &0, &10);
+    assert_eq!(
+        by_ref,
+        soroban_sdk::vec![&setup.env, legacy_authority, unindexed.clone()]
+    );
+
+    // The free-form metadata became the profile name
+    let migrated = setup.resolver_client.get_authority_data(&unindexed).unwrap();
+    assert_eq!(migrated.profile, test_profile(&setup.env, "Early Org"));
+    assert_eq!(migrated.profile_version, 1);
+    assert_eq!(migrated.registration_time, 1_600_000_000);
+    assert_eq!(migrated.status, AuthorityStatus::Active);
+    assert_eq!(migrated.expires_at, None);
+    assert_eq!(migrated.level, VerificationLevel::Basic);
+    assert!(setup.resolver_client.is_authority(&unindexed));
+
+    // Indexing is idempotent
+    setup.resolver_client.migrate_legacy_entries(&setup.admin, &batch);
+    assert_eq!(setup.resolver_client.authority_count(), 2);
+}
+
+#[test]
+fn test_original_layout_is_read_before_legacy_entries_migrate() {
+    let setup = setup_env(true);
+    let authority = Address::generate(&setup.env);
+    let data = LegacyAuthorityData {
+        address: authority.clone(),
+        metadata: SorobanString::from_str(&setup.env, "Early Org"),
+        registration_time: 1_600_000_000,
+        ref_id: SorobanString::from_str(&setup.env, "legacy-ref"),
+    };
+    let payment = LegacyPaymentRecord {
+        recipient: authority.clone(),
+        timestamp: 1_600_000_000,
+        ref_id: SorobanString::from_str(&setup.env, "legacy-ref"),
+        amount_paid: REGISTRATION_FEE,
+    };
+    // An upgraded original deployment, right after `migrate`
+    setup.env.as_contract(&setup.resolver_address, || {
+        let storage = setup.env.storage().persistent();
+        storage.set(&(DataKey::Authority, authority.clone()), &data);
+        storage.set(&(DataKey::PaymentRecord, authority.clone()), &payment);
+        setup.env.storage().instance().remove(&DataKey::ContractVersion);
+    });
+    setup.resolver_client.migrate(&setup.admin);
+
+    assert!(setup.resolver_client.is_authority(&authority));
+    let read = setup.resolver_client.get_authority_data(&authority).unwrap();
+    assert_eq!(read.profile, test_profile(&setup.env, "Early Org"));
+    assert_eq!(read.level, VerificationLevel::Basic);
+    assert!(setup.resolver_client.has_confirmed_payment(&authority));
+    let record = setup.resolver_client.get_payment_record(&authority).unwrap();
+    assert_eq!(record.status, PaymentStatus::Approved);
+    assert_eq!(record.token, setup.token_address);
+
+    // Migrating the entries changes nothing a reader can see
+    let batch = soroban_sdk::vec![&setup.env, authority.clone()];
+    assert_eq!(setup.resolver_client.migrate_legacy_entries(&setup.admin, &batch), 2);
+    assert_eq!(setup.resolver_client.get_authority_data(&authority), Some(read));
+    assert_eq!(
+        setup
+            .resolver_client
+            .get_payment_record(&authority)
+            .unwrap()
+            .amount_paid,
+        REGISTRATION_FEE
+    );
 }
 
 // ══════════════════════════════════════════════════════════════════════════════
@@ -627,7 +2246,7 @@ fn test_unauthorized_operations() {
     let result1 = setup.resolver_client.try_admin_register_authority(
         &non_admin,
         &some_authority,
-        &SorobanString::from_str(&setup.env, "Meta"),
+        &test_profile(&setup.env, "Meta"),
     );
     assert!(
         matches!(result1.err().unwrap(), Ok(Error::NotAuthorized)),
@@ -635,7 +2254,9 @@ fn test_unauthorized_operations() {
     );
 
     let non_recipient_non_authority = Address::generate(&setup.env);
-    let withdraw_attempt_result = setup.resolver_client.try_withdraw_levies(&non_recipient_non_authority);
+    let withdraw_attempt_result = setup
+        .resolver_client
+        .try_withdraw_levies(&non_recipient_non_authority, &setup.token_address);
     assert!(
         matches!(withdraw_attempt_result.err().unwrap(), Ok(Error::NotAuthorized)),
         "Unauthorized withdraw_levies did not fail correctly"
@@ -645,11 +2266,77 @@ fn test_unauthorized_operations() {
 // Optional: Add tests for specific auth scenarios without mock_all_auths
 // These require careful crafting of `env.mock_auths()` calls.
 
-/*
 #[test]
 fn test_collect_levies() {
-    // TODO: Complex levy collection moved to fee collection resolver
-    // RECOMMENDATION: Use fee collection resolver for multi-schema levy collection
-    // IMPACT: Authority resolver focuses on authority management only
+    let setup = setup_env(true);
+    let (attester, recipient, schema_uid) = setup_levy_schema(&setup);
+    setup.token_admin_client.mint(&attester, &MINT_AMOUNT);
+
+    // A second schema pays a different levy to the same recipient
+    let other_schema = BytesN::random(&setup.env);
+    let other_levy = 2_0000000;
+    let rules = SchemaRules {
+        levy_amount: Some(other_levy),
+        levy_recipient: Some(recipient.clone()),
+        ..open_schema_rules(&setup.env)
+    };
+    setup
+        .resolver_client
+        .admin_register_schema(&setup.admin, &other_schema, &rules);
+
+    for schema in [&schema_uid, &schema_uid, &other_schema] {
+        let attestation = create_dummy_attestation(&setup.env, &attester, schema, None);
+        setup.resolver_client.attest(&attestation);
+    }
+
+    let total = 2 * DEFAULT_LEVY + other_levy;
+    assert_eq!(
+        setup
+            .resolver_client
+            .get_collected_levies(&recipient, &setup.token_address),
+        total
+    );
+    assert_eq!(setup.token_client.balance(&setup.resolver_address), total);
+    assert_eq!(setup.token_client.balance(&attester), MINT_AMOUNT - total);
+}
+
+#[test]
+fn test_levies_are_withdrawn_in_the_token_they_were_paid_in() {
+    let setup = setup_env(true);
+    let (attester, recipient, schema_uid) = setup_levy_schema(&setup);
+    setup.token_admin_client.mint(&attester, &MINT_AMOUNT);
+    let attestation = create_dummy_attestation(&setup.env, &attester, &schema_uid, None);
+    setup.resolver_client.attest(&attestation);
+
+    // Rotate the primary fee token and collect a levy in the new one
+    let (usdc, usdc_client, usdc_admin_client) = create_token_contract(&setup.env, &setup.admin);
+    setup
+        .resolver_client
+        .set_registration_fee(&setup.admin, &REGISTRATION_FEE, &usdc);
+    usdc_admin_client.mint(&attester, &MINT_AMOUNT);
+    // Unrelated funds the contract holds in the new token
+    usdc_admin_client.mint(&setup.resolver_address, &MINT_AMOUNT);
+    setup.resolver_client.attest(&attestation);
+
+    let mut levies = levies_in_primary_token(&setup, DEFAULT_LEVY);
+    levies.set(usdc.clone(), DEFAULT_LEVY);
+    assert_eq!(setup.resolver_client.get_balances(&recipient).levies, levies);
+
+    // Each balance is paid out in its own token only
+    setup.resolver_client.withdraw_levies(&recipient, &setup.token_address);
+    assert_eq!(setup.token_client.balance(&recipient), DEFAULT_LEVY);
+    assert_eq!(usdc_client.balance(&recipient), 0);
+    assert_eq!(usdc_client.balance(&setup.resolver_address), MINT_AMOUNT + DEFAULT_LEVY);
+    let result = setup
+        .resolver_client
+        .try_withdraw_levies(&recipient, &setup.token_address);
+    assert!(matches!(result.err().unwrap(), Ok(Error::NothingToWithdraw)));
+
+    setup.resolver_client.withdraw_levies(&recipient, &usdc);
+    assert_eq!(usdc_client.balance(&recipient), DEFAULT_LEVY);
+    assert_eq!(usdc_client.balance(&setup.resolver_address), MINT_AMOUNT);
+    assert_eq!(
+        setup.resolver_client.get_balances(&recipient).levies,
+        Map::new(&setup.env)
+    );
 }
-*/
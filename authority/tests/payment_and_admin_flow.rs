@@ -1,12 +1,19 @@
 extern crate std;
 
 use soroban_sdk::{
-    testutils::{Address as _, BytesN as _, Events as _, Ledger, LedgerInfo},
-    token, Address, Bytes, BytesN, Env, String as SorobanString, TryFromVal,
+    testutils::{Address as _, BytesN as _, Events as _, Ledger, LedgerInfo, MockAuth, MockAuthInvoke},
+    token,
+    xdr::ToXdr,
+    Address, Bytes, BytesN, Env, IntoVal, String as SorobanString, Symbol, TryFromVal, Val,
 };
 
-use authority::{AuthorityResolverContract, AuthorityResolverContractClient};
-use resolvers::ResolverAttestationData as ResolverAttestation;
+use authority::{
+    AuthorityProfile, AuthorityResolverContract, AuthorityResolverContractClient, AuthorityStatus, Error,
+    PaymentStatus, SchemaRules, VerificationLevel, VerificationPayload, ATTESTER_ADDED, ATTESTER_REMOVED,
+    AUTHORITY_REGISTERED, AUTHORITY_REVOKED, FEES_WITHDRAWN, PAYMENT_APPROVED, PAYMENT_RECEIVED, PAYMENT_REFUNDED,
+    PAYMENT_REJECTED, PROTOCOL_UPDATED, PUBLIC_REGISTRATION_REF_ID, REGISTRATION_FEE_UPDATED, VALIDITY_UPDATED,
+};
+use resolvers::{ResolverAttestationData as ResolverAttestation, ResolverError, ResolverType};
 
 const REGISTRATION_FEE: i128 = 100_0000000; // 100 XLM
 const REWARD_AMOUNT: i128 = 5_0000000; // 5 tokens for reward tests
@@ -16,6 +23,8 @@ struct TestEnv {
     admin: Address,
     contract_id: Address,
     token_address: Address,
+    protocol: Address,
+    platform: Address,
 }
 
 fn setup_env() -> TestEnv {
@@ -46,29 +55,56 @@ fn setup_env() -> TestEnv {
 
     // Create dummy wasm hash for initialization
     let token_wasm_hash = BytesN::from_array(&env, &[0u8; 32]);
-    client.initialize(&admin, &token_address, &token_wasm_hash);
+    let protocol = Address::generate(&env);
+    client.initialize(&admin, &token_address, &token_wasm_hash, &protocol);
+    let platform = Address::generate(&env);
+    client.add_platform_attester(&admin, &platform);
 
     TestEnv {
         env,
         admin,
         contract_id,
         token_address,
+        protocol,
+        platform,
+    }
+}
+
+// Helper function to create a profile with only a display name
+fn test_profile(env: &Env, name: &str) -> AuthorityProfile {
+    AuthorityProfile {
+        name: SorobanString::from_str(env, name),
+        website: None,
+        jurisdiction: None,
+        registry_id: None,
+        contact: None,
+        documents_hash: None,
+    }
+}
+
+// Helper function for a Basic-tier verification payload that follows the payment's validity
+fn verification_payload(env: &Env, ref_id: &str) -> VerificationPayload {
+    VerificationPayload {
+        profile: test_profile(env, "Verified Org"),
+        ref_id: SorobanString::from_str(env, ref_id),
+        tier: 0,
+        valid_until: None,
     }
 }
 
 // Helper function for building test attestations (resolver interface)
-fn build_resolver_attestation(env: &Env, attester: &Address) -> ResolverAttestation {
+fn build_resolver_attestation(env: &Env, attester: &Address, recipient: &Address, ref_id: &str) -> ResolverAttestation {
     ResolverAttestation {
         uid: BytesN::random(env),
         schema_uid: BytesN::random(env),
-        recipient: Address::generate(env),
+        recipient: recipient.clone(),
         attester: attester.clone(),
         time: env.ledger().timestamp(),
         expiration_time: 0,
         revocation_time: 0,
         revocable: true,
         ref_uid: Bytes::new(env),
-        data: Bytes::new(env),
+        data: verification_payload(env, ref_id).to_xdr(env),
         value: 0,
     }
 }
@@ -87,6 +123,15 @@ fn pay_fee_records_payment_and_event() {
     let ref_id = SorobanString::from_str(env, "org-1");
     client.pay_verification_fee(&payer, &ref_id, &setup.token_address);
 
+    let events = env.events().all();
+    let payment_event = events
+        .iter()
+        .any(|(_, topics, _)| topics.get(0).and_then(|v| Symbol::try_from_val(env, &v).ok()) == Some(PAYMENT_RECEIVED));
+    assert!(payment_event, "PAYMENT_RECEIVED event not found: {:?}", events);
+
+    // Eligibility waits for due diligence
+    assert!(!client.has_confirmed_payment(&payer));
+    client.approve_payment(&setup.admin, &payer);
     assert!(client.has_confirmed_payment(&payer));
     let record = client.get_payment_record(&payer).unwrap();
     assert_eq!(record.ref_id, ref_id);
@@ -94,16 +139,7 @@ fn pay_fee_records_payment_and_event() {
 
     let contract_balance = token_client.balance(&setup.contract_id);
     assert_eq!(contract_balance, REGISTRATION_FEE);
-
-    let events = env.events().all();
-    let _payment_event = events.iter().any(|(_, topics, _)| {
-        topics.get(0).and_then(|v| SorobanString::try_from_val(env, &v).ok())
-            == Some(SorobanString::from_str(env, "PAYMENT_RECEIVED"))
-    });
-    // TODO: PAYMENT_RECEIVED event not emitted during fee payment
-    // RECOMMENDATION: Emit PAYMENT_RECEIVED in pay_verification_fee for auditability
-    // IMPACT: Platforms cannot monitor verification payments
-    // assert!(payment_event, "PAYMENT_RECEIVED event not found: {:?}", events);
+    assert_eq!(client.get_fee_ledger().total_collected, REGISTRATION_FEE);
 }
 
 #[test]
@@ -126,6 +162,47 @@ fn double_payment_updates_record() {
     assert_eq!(token_client.balance(&setup.contract_id), REGISTRATION_FEE * 2);
 }
 
+#[test]
+fn payment_history_keeps_every_payment() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let payer = Address::generate(env);
+    let token_admin = token::StellarAssetClient::new(env, &setup.token_address);
+    token_admin.mint(&payer, &(REGISTRATION_FEE * 3));
+
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    let refs = ["first", "second", "third"].map(|r| SorobanString::from_str(env, r));
+    for ref_id in refs.iter() {
+        client.pay_verification_fee(&payer, ref_id, &setup.token_address);
+    }
+
+    assert_eq!(client.get_payment_count(&payer), 3);
+    assert_eq!(client.get_total_paid(&payer), REGISTRATION_FEE * 3);
+
+    let history = client.get_payment_history(&payer, &0, &10);
+    assert_eq!(history.len(), 3);
+    for (sequence, record) in history.iter().enumerate() {
+        assert_eq!(record.sequence, sequence as u32);
+        assert_eq!(record.ref_id, refs[sequence]);
+    }
+
+    // Pages start at a sequence number; the latest record still drives eligibility
+    let page = client.get_payment_history(&payer, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().ref_id, refs[1]);
+    assert_eq!(client.get_payment_record(&payer).unwrap(), history.get(2).unwrap());
+    client.approve_payment(&setup.admin, &payer);
+    assert!(client.has_confirmed_payment(&payer));
+    assert_eq!(
+        client.get_payment_history(&payer, &2, &1).get(0).unwrap().status,
+        PaymentStatus::Approved
+    );
+
+    let stranger = Address::generate(env);
+    assert_eq!(client.get_payment_history(&stranger, &0, &10).len(), 0);
+    assert_eq!(client.get_total_paid(&stranger), 0);
+}
+
 #[test]
 #[should_panic]
 fn pay_fee_insufficient_funds_panics() {
@@ -143,13 +220,10 @@ fn onattest_blocks_unpaid() {
     let setup = setup_env();
     let env = &setup.env;
     let unpaid = Address::generate(env);
-    let att = build_resolver_attestation(env, &unpaid);
+    let att = build_resolver_attestation(env, &setup.platform, &unpaid, "org-unpaid");
     let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
-    let _res = client.try_onattest(&att);
-    // TODO: Need to implement onattest to check payment status
-    // RECOMMENDATION: Implement resolver interface methods that check payment before allowing attestation
-    // IMPACT: Currently unpaid users can bypass payment requirements
-    // assert!(matches!(res, Err(Ok(ResolverError::NotAuthorized))));
+    let res = client.try_onattest(&att);
+    assert!(matches!(res, Err(Ok(ResolverError::ValidationFailed))));
 }
 
 #[test]
@@ -162,25 +236,492 @@ fn before_and_onresolve_with_payment() {
     let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
     let ref_id = SorobanString::from_str(env, "org-3");
     client.pay_verification_fee(&payer, &ref_id, &setup.token_address);
+    client.approve_payment(&setup.admin, &payer);
 
-    let _att = build_resolver_attestation(env, &payer);
+    let att = build_resolver_attestation(env, &setup.platform, &payer, "org-3");
 
-    // TODO: Implement onattest and onresolve resolver hooks
-    // RECOMMENDATION: Add resolver interface implementation for authority registration flow
-    // IMPACT: Cannot validate payment requirements before attestation
-    // assert!(client.onattest(&att));
-    // client.onresolve(&att);
-    // assert!(client.is_authority(&payer));
+    assert!(client.onattest(&att));
+    client.onresolve(&att.uid, &setup.platform);
 
     let events = env.events().all();
-    let _registered_event = events.iter().any(|(_, topics, _)| {
-        topics.get(0).and_then(|v| SorobanString::try_from_val(env, &v).ok())
-            == Some(SorobanString::from_str(env, "AUTHORITY_REGISTERED"))
+    let registered_event = events.iter().any(|(_, topics, _)| {
+        topics.get(0).and_then(|v| Symbol::try_from_val(env, &v).ok()) == Some(AUTHORITY_REGISTERED)
     });
-    // TODO: AUTHORITY_REGISTERED event not emitted after attestation
-    // RECOMMENDATION: Ensure onresolve publishes AUTHORITY_REGISTERED
-    // IMPACT: Authority phone book updates cannot be tracked off-chain
-    // assert!(registered_event, "AUTHORITY_REGISTERED event missing: {:?}", events);
+    assert!(registered_event, "AUTHORITY_REGISTERED event missing: {:?}", events);
+    assert!(client.is_authority(&payer));
+
+    // The profile comes from the attestation payload
+    let data = client.get_authority_data(&payer).unwrap();
+    assert_eq!(data.profile, test_profile(env, "Verified Org"));
+    assert_eq!(data.profile_version, 1);
+}
+
+#[test]
+fn register_authority_applies_through_review_and_attestation() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    let token_admin = token::StellarAssetClient::new(env, &setup.token_address);
+    let applicant = Address::generate(env);
+    let sponsor = Address::generate(env);
+    let nominee = Address::generate(env);
+    token_admin.mint(&applicant, &REGISTRATION_FEE);
+    token_admin.mint(&sponsor, &REGISTRATION_FEE);
+
+    client.register_authority(&applicant, &applicant, &test_profile(env, "Applicant"));
+    client.register_authority(&sponsor, &nominee, &test_profile(env, "Nominee"));
+    client.accept_authority_nomination(&nominee);
+
+    for org in [&applicant, &nominee] {
+        // Unreviewed applications cannot be attested
+        let att = build_resolver_attestation(env, &setup.platform, org, PUBLIC_REGISTRATION_REF_ID);
+        assert_eq!(client.try_onattest(&att), Err(Ok(ResolverError::ValidationFailed)));
+
+        client.approve_payment(&setup.admin, org);
+        assert!(!client.is_authority(org));
+        assert!(client.onattest(&att));
+        client.onresolve(&att.uid, &setup.platform);
+        assert!(client.is_authority(org));
+        assert_eq!(
+            client.get_authority_data(org).unwrap().profile,
+            test_profile(env, "Verified Org")
+        );
+    }
+    assert_eq!(client.get_authority_sponsor(&nominee), Some(sponsor));
+}
+
+#[test]
+fn onattest_validates_verification_payload() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, &setup.token_address).mint(&payer, &REGISTRATION_FEE);
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    client.pay_verification_fee(
+        &payer,
+        &SorobanString::from_str(env, "org-profile"),
+        &setup.token_address,
+    );
+    client.approve_payment(&setup.admin, &payer);
+
+    let mut att = build_resolver_attestation(env, &setup.platform, &payer, "org-profile");
+    att.data = Bytes::new(env);
+    let res = client.try_onattest(&att);
+    assert!(matches!(res, Err(Ok(ResolverError::InvalidAttestation))));
+
+    let mut payload = verification_payload(env, "org-profile");
+    payload.profile = test_profile(env, "");
+    att.data = payload.to_xdr(env);
+    let res = client.try_onattest(&att);
+    assert!(matches!(res, Err(Ok(ResolverError::InvalidAttestation))));
+
+    let mut payload = verification_payload(env, "org-profile");
+    payload.tier = 3;
+    att.data = payload.to_xdr(env);
+    let res = client.try_onattest(&att);
+    assert!(matches!(res, Err(Ok(ResolverError::InvalidAttestation))));
+
+    let mut payload = verification_payload(env, "org-profile");
+    payload.valid_until = Some(env.ledger().timestamp());
+    att.data = payload.to_xdr(env);
+    let res = client.try_onattest(&att);
+    assert!(matches!(res, Err(Ok(ResolverError::InvalidAttestation))));
+
+    // The payload must be about the organization that paid
+    att.data = verification_payload(env, "org-other").to_xdr(env);
+    let res = client.try_onattest(&att);
+    assert!(matches!(res, Err(Ok(ResolverError::ValidationFailed))));
+}
+
+#[test]
+fn onresolve_stores_decoded_payload() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    let year = 365 * 24 * 60 * 60;
+    client.set_validity_period(&setup.admin, &Some(year), &0);
+
+    let payer = Address::generate(env);
+    let token_admin = token::StellarAssetClient::new(env, &setup.token_address);
+    token_admin.mint(&payer, &(REGISTRATION_FEE * 2));
+    client.pay_verification_fee(&payer, &SorobanString::from_str(env, "org-kyb"), &setup.token_address);
+    client.approve_payment(&setup.admin, &payer);
+
+    let mut payload = verification_payload(env, "org-kyb");
+    payload.profile = test_profile(env, "KYB Org");
+    payload.tier = 1;
+    payload.valid_until = Some(env.ledger().timestamp() + 1000);
+    let mut att = build_resolver_attestation(env, &setup.platform, &payer, "org-kyb");
+    att.data = payload.to_xdr(env);
+    assert!(client.onattest(&att));
+    client.onresolve(&att.uid, &setup.platform);
+
+    let data = client.get_authority_data(&payer).unwrap();
+    assert_eq!(data.profile, test_profile(env, "KYB Org"));
+    assert_eq!(data.ref_id, SorobanString::from_str(env, "org-kyb"));
+    assert_eq!(data.level, VerificationLevel::KybVerified);
+    // The attested validity ends before the paid year
+    assert_eq!(data.expires_at, Some(env.ledger().timestamp() + 1000));
+
+    // Paying for another organization before onresolve voids the attestation
+    let att = build_resolver_attestation(env, &setup.platform, &payer, "org-kyb");
+    assert!(client.onattest(&att));
+    client.pay_verification_fee(&payer, &SorobanString::from_str(env, "org-new"), &setup.token_address);
+    client.approve_payment(&setup.admin, &payer);
+    let res = client.try_onresolve(&att.uid, &setup.platform);
+    assert!(matches!(res, Err(Ok(ResolverError::ValidationFailed))));
+}
+
+#[test]
+fn renewals_stop_at_the_attested_validity() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    client.set_validity_period(&setup.admin, &Some(1000), &0);
+    let now = env.ledger().timestamp();
+
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, &setup.token_address).mint(&payer, &(REGISTRATION_FEE * 4));
+    client.pay_verification_fee(&payer, &SorobanString::from_str(env, "org-kyb"), &setup.token_address);
+    client.approve_payment(&setup.admin, &payer);
+    let attest = |valid_until: u64| {
+        let mut payload = verification_payload(env, "org-kyb");
+        payload.valid_until = Some(valid_until);
+        let mut att = build_resolver_attestation(env, &setup.platform, &payer, "org-kyb");
+        att.data = payload.to_xdr(env);
+        assert!(client.onattest(&att));
+        client.onresolve(&att.uid, &setup.platform);
+    };
+    attest(now + 2500);
+    assert_eq!(
+        client.get_authority_data(&payer).unwrap().verified_until,
+        Some(now + 2500)
+    );
+
+    assert_eq!(client.renew_authority(&payer, &setup.token_address), now + 2000);
+    // The last renewal is cut short at the attested validity
+    assert_eq!(client.renew_authority(&payer, &setup.token_address), now + 2500);
+    let res = client.try_renew_authority(&payer, &setup.token_address);
+    assert!(matches!(res, Err(Ok(Error::VerificationExpired))));
+    assert_eq!(client.get_payment_count(&payer), 3);
+
+    // A new attestation lets the organization renew again
+    attest(now + 10_000);
+    assert_eq!(client.renew_authority(&payer, &setup.token_address), now + 3500);
+}
+
+// Authorizes a single hook call as `caller`
+fn mock_hook_auth(env: &Env, contract_id: &Address, caller: &Address, fn_name: &str, args: soroban_sdk::Vec<Val>) {
+    env.mock_auths(&[MockAuth {
+        address: caller,
+        invoke: &MockAuthInvoke {
+            contract: contract_id,
+            fn_name,
+            args,
+            sub_invokes: &[],
+        },
+    }]);
+}
+
+#[test]
+fn hooks_reject_direct_calls() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, &setup.token_address).mint(&payer, &REGISTRATION_FEE);
+    client.pay_verification_fee(
+        &payer,
+        &SorobanString::from_str(env, "org-direct"),
+        &setup.token_address,
+    );
+    client.approve_payment(&setup.admin, &payer);
+    let att = build_resolver_attestation(env, &setup.platform, &payer, "org-direct");
+
+    // The payer cannot call the hooks itself with a fabricated attestation
+    mock_hook_auth(
+        env,
+        &setup.contract_id,
+        &payer,
+        "onattest",
+        (att.clone(),).into_val(env),
+    );
+    assert!(client.try_onattest(&att).is_err());
+    mock_hook_auth(
+        env,
+        &setup.contract_id,
+        &payer,
+        "onrevoke",
+        (att.clone(),).into_val(env),
+    );
+    assert!(client.try_onrevoke(&att).is_err());
+
+    // The protocol can
+    mock_hook_auth(
+        env,
+        &setup.contract_id,
+        &setup.protocol,
+        "onattest",
+        (att.clone(),).into_val(env),
+    );
+    assert!(client.onattest(&att));
+
+    let resolve_args = (att.uid.clone(), setup.platform.clone()).into_val(env);
+    mock_hook_auth(env, &setup.contract_id, &payer, "onresolve", resolve_args);
+    assert!(client.try_onresolve(&att.uid, &setup.platform).is_err());
+    assert!(!client.is_authority(&payer));
+
+    let resolve_args = (att.uid.clone(), setup.platform.clone()).into_val(env);
+    mock_hook_auth(env, &setup.contract_id, &setup.protocol, "onresolve", resolve_args);
+    client.onresolve(&att.uid, &setup.platform);
+    assert!(client.is_authority(&payer));
+}
+
+#[test]
+fn protocol_contract_is_rotated_by_owner() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    let new_protocol = Address::generate(env);
+
+    let res = client.try_set_protocol_contract(&Address::generate(env), &new_protocol);
+    assert!(matches!(res, Err(Ok(Error::NotAuthorized))));
+
+    client.set_protocol_contract(&setup.admin, &new_protocol);
+    let events = env.events().all();
+    let updated_event = events
+        .iter()
+        .any(|(_, topics, _)| topics.get(0).and_then(|v| Symbol::try_from_val(env, &v).ok()) == Some(PROTOCOL_UPDATED));
+    assert!(updated_event, "PROTOCOL_UPDATED event missing: {:?}", events);
+    assert_eq!(client.get_protocol_contract(), Some(new_protocol.clone()));
+
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, &setup.token_address).mint(&payer, &REGISTRATION_FEE);
+    client.pay_verification_fee(
+        &payer,
+        &SorobanString::from_str(env, "org-rotate"),
+        &setup.token_address,
+    );
+    client.approve_payment(&setup.admin, &payer);
+    let att = build_resolver_attestation(env, &setup.platform, &payer, "org-rotate");
+
+    // The previous protocol is no longer trusted
+    mock_hook_auth(
+        env,
+        &setup.contract_id,
+        &setup.protocol,
+        "onattest",
+        (att.clone(),).into_val(env),
+    );
+    assert!(client.try_onattest(&att).is_err());
+    mock_hook_auth(
+        env,
+        &setup.contract_id,
+        &new_protocol,
+        "onattest",
+        (att.clone(),).into_val(env),
+    );
+    assert!(client.onattest(&att));
+}
+
+#[test]
+fn onattest_requires_platform_attester_and_paying_recipient() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, &setup.token_address).mint(&payer, &REGISTRATION_FEE);
+    client.pay_verification_fee(&payer, &SorobanString::from_str(env, "org-pa"), &setup.token_address);
+    client.approve_payment(&setup.admin, &payer);
+
+    // A paying organization cannot attest for itself
+    let att = build_resolver_attestation(env, &payer, &payer, "org-pa");
+    let res = client.try_onattest(&att);
+    assert!(matches!(res, Err(Ok(ResolverError::NotAuthorized))));
+
+    // The platform cannot verify an organization that did not pay
+    let att = build_resolver_attestation(env, &setup.platform, &Address::generate(env), "org-pa");
+    let res = client.try_onattest(&att);
+    assert!(matches!(res, Err(Ok(ResolverError::ValidationFailed))));
+
+    // The recipient, not the attester, becomes the authority
+    let att = build_resolver_attestation(env, &setup.platform, &payer, "org-pa");
+    assert!(client.onattest(&att));
+    client.onresolve(&att.uid, &setup.platform);
+    assert!(client.is_authority(&payer));
+    assert!(!client.is_authority(&setup.platform));
+}
+
+#[test]
+fn platform_attesters_are_managed_by_owner() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    let attester = Address::generate(env);
+    assert!(!client.is_platform_attester(&attester));
+
+    let res = client.try_add_platform_attester(&Address::generate(env), &attester);
+    assert!(matches!(res, Err(Ok(Error::NotAuthorized))));
+
+    client.add_platform_attester(&setup.admin, &attester);
+    let added_event =
+        env.events().all().iter().any(|(_, topics, _)| {
+            topics.get(0).and_then(|v| Symbol::try_from_val(env, &v).ok()) == Some(ATTESTER_ADDED)
+        });
+    assert!(added_event);
+    assert!(client.is_platform_attester(&attester));
+    let res = client.try_add_platform_attester(&setup.admin, &attester);
+    assert!(matches!(res, Err(Ok(Error::RoleAlreadyGranted))));
+
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, &setup.token_address).mint(&payer, &REGISTRATION_FEE);
+    client.pay_verification_fee(&payer, &SorobanString::from_str(env, "org-trust"), &setup.token_address);
+    client.approve_payment(&setup.admin, &payer);
+    let att = build_resolver_attestation(env, &attester, &payer, "org-trust");
+    assert!(client.onattest(&att));
+
+    // Removing the attester voids its unresolved attestations
+    client.remove_platform_attester(&setup.admin, &attester);
+    let removed_event =
+        env.events().all().iter().any(|(_, topics, _)| {
+            topics.get(0).and_then(|v| Symbol::try_from_val(env, &v).ok()) == Some(ATTESTER_REMOVED)
+        });
+    assert!(removed_event);
+    assert!(!client.is_platform_attester(&attester));
+    let res = client.try_onresolve(&att.uid, &attester);
+    assert!(matches!(res, Err(Ok(ResolverError::NotAuthorized))));
+    assert!(!client.is_authority(&payer));
+    let res = client.try_remove_platform_attester(&setup.admin, &attester);
+    assert!(matches!(res, Err(Ok(Error::RoleNotGranted))));
+}
+
+#[test]
+fn onresolve_requires_prior_onattest() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let payer = Address::generate(env);
+    let token_admin = token::StellarAssetClient::new(env, &setup.token_address);
+    token_admin.mint(&payer, &REGISTRATION_FEE);
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    client.pay_verification_fee(&payer, &SorobanString::from_str(env, "org-5"), &setup.token_address);
+    client.approve_payment(&setup.admin, &payer);
+
+    let res = client.try_onresolve(&BytesN::random(env), &payer);
+    assert!(matches!(res, Err(Ok(ResolverError::InvalidAttestation))));
+    assert!(!client.is_authority(&payer));
+}
+
+#[test]
+fn onrevoke_then_onresolve_revokes_authority() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let payer = Address::generate(env);
+    let token_admin = token::StellarAssetClient::new(env, &setup.token_address);
+    token_admin.mint(&payer, &REGISTRATION_FEE);
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    client.pay_verification_fee(&payer, &SorobanString::from_str(env, "org-6"), &setup.token_address);
+    client.approve_payment(&setup.admin, &payer);
+
+    let att = build_resolver_attestation(env, &setup.platform, &payer, "org-6");
+    client.onattest(&att);
+    client.onresolve(&att.uid, &setup.platform);
+    assert!(client.is_authority(&payer));
+
+    // Only the original attester may revoke
+    let mut foreign = att.clone();
+    foreign.attester = Address::generate(env);
+    let res = client.try_onrevoke(&foreign);
+    assert!(matches!(res, Err(Ok(ResolverError::NotAuthorized))));
+
+    assert!(client.onrevoke(&att));
+    client.onresolve(&att.uid, &setup.platform);
+
+    let events = env.events().all();
+    let removed_event = events.iter().any(|(_, topics, _)| {
+        topics.get(0).and_then(|v| Symbol::try_from_val(env, &v).ok()) == Some(AUTHORITY_REVOKED)
+    });
+    assert!(removed_event, "AUTHORITY_REVOKED event missing: {:?}", events);
+    assert!(!client.is_authority(&payer));
+    assert_eq!(
+        client.get_authority_data(&payer).unwrap().status,
+        AuthorityStatus::Revoked
+    );
+
+    // A revoked authority cannot be re-verified
+    let again = build_resolver_attestation(env, &setup.platform, &payer, "org-6");
+    let res = client.try_onattest(&again);
+    assert!(matches!(res, Err(Ok(ResolverError::NotAuthorized))));
+}
+
+#[test]
+fn metadata_reports_authority_type() {
+    let setup = setup_env();
+    let client = AuthorityResolverContractClient::new(&setup.env, &setup.contract_id);
+    assert_eq!(client.metadata().resolver_type, ResolverType::Authority);
+}
+
+#[test]
+fn registration_fee_defaults_and_is_configurable() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    assert_eq!(client.get_registration_fee(), REGISTRATION_FEE);
+
+    let new_fee = 25_0000000;
+    client.set_registration_fee(&setup.admin, &new_fee, &setup.token_address);
+
+    let events = env.events().all();
+    let fee_event = events.iter().any(|(_, topics, _)| {
+        topics.get(0).and_then(|v| Symbol::try_from_val(env, &v).ok()) == Some(REGISTRATION_FEE_UPDATED)
+    });
+    assert!(fee_event, "REGISTRATION_FEE_UPDATED event missing: {:?}", events);
+    assert_eq!(client.get_registration_fee(), new_fee);
+
+    // Fee payments charge the stored amount
+    let payer = Address::generate(env);
+    let token_admin = token::StellarAssetClient::new(env, &setup.token_address);
+    let token_client = token::Client::new(env, &setup.token_address);
+    token_admin.mint(&payer, &REGISTRATION_FEE);
+    client.pay_verification_fee(&payer, &SorobanString::from_str(env, "org-fee"), &setup.token_address);
+    assert_eq!(client.get_payment_record(&payer).unwrap().amount_paid, new_fee);
+    assert_eq!(token_client.balance(&payer), REGISTRATION_FEE - new_fee);
+
+    // So do public registrations
+    let caller = Address::generate(env);
+    token_admin.mint(&caller, &REGISTRATION_FEE);
+    client.register_authority(&caller, &Address::generate(env), &test_profile(env, "meta"));
+    assert_eq!(token_client.balance(&caller), REGISTRATION_FEE - new_fee);
+}
+
+#[test]
+fn set_registration_fee_requires_owner() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+
+    let non_admin = Address::generate(env);
+    let res = client.try_set_registration_fee(&non_admin, &1, &setup.token_address);
+    assert!(matches!(res, Err(Ok(Error::NotAuthorized))));
+
+    let res = client.try_set_registration_fee(&setup.admin, &-1, &setup.token_address);
+    assert!(matches!(res, Err(Ok(Error::InvalidFeeAmount))));
+    assert_eq!(client.get_registration_fee(), REGISTRATION_FEE);
+}
+
+#[test]
+fn pay_fee_rejects_unconfigured_token() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+
+    // A token anyone can mint for free must not count as payment
+    let payer = Address::generate(env);
+    let fake_token = env.register_stellar_asset_contract_v2(payer.clone()).address();
+    token::StellarAssetClient::new(env, &fake_token).mint(&payer, &REGISTRATION_FEE);
+
+    let res = client.try_pay_verification_fee(&payer, &SorobanString::from_str(env, "org-fake"), &fake_token);
+    assert!(matches!(res, Err(Ok(Error::InvalidFeeToken))));
+    assert!(!client.has_confirmed_payment(&payer));
 }
 
 #[test]
@@ -195,11 +736,592 @@ fn admin_withdraw_fees_requires_admin() {
     client.pay_verification_fee(&payer, &ref_id, &setup.token_address);
 
     let non_admin = Address::generate(env);
-    let _res = client.try_admin_withdraw_fees(&non_admin, &setup.token_address, &REGISTRATION_FEE);
-    // TODO: Implement admin_withdraw_fees function
-    // RECOMMENDATION: Add admin-only function to withdraw collected verification fees
-    // IMPACT: Collected fees cannot be withdrawn by contract owner
-    // assert!(matches!(res, Err(Ok(Error::NotAuthorized))));
+    let res = client.try_admin_withdraw_fees(&non_admin, &setup.token_address, &REGISTRATION_FEE);
+    assert!(matches!(res, Err(Ok(Error::NotAuthorized))));
+}
+
+#[test]
+fn admin_withdraw_fees_updates_ledger_and_emits_event() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let payer = Address::generate(env);
+    let token_admin = token::StellarAssetClient::new(env, &setup.token_address);
+    let token_client = token::Client::new(env, &setup.token_address);
+    token_admin.mint(&payer, &REGISTRATION_FEE);
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    client.pay_verification_fee(&payer, &SorobanString::from_str(env, "org-7"), &setup.token_address);
+
+    let partial = REGISTRATION_FEE / 4;
+    client.admin_withdraw_fees(&setup.admin, &setup.token_address, &partial);
+
+    let events = env.events().all();
+    let withdrawn_event = events
+        .iter()
+        .any(|(_, topics, _)| topics.get(0).and_then(|v| Symbol::try_from_val(env, &v).ok()) == Some(FEES_WITHDRAWN));
+    assert!(withdrawn_event, "FEES_WITHDRAWN event missing: {:?}", events);
+
+    assert_eq!(token_client.balance(&setup.admin), partial);
+    let ledger = client.get_fee_ledger();
+    assert_eq!(ledger.total_collected, REGISTRATION_FEE);
+    assert_eq!(ledger.total_withdrawn, partial);
+}
+
+#[test]
+fn admin_withdraw_fees_rejects_amount_above_unallocated() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let payer = Address::generate(env);
+    let token_admin = token::StellarAssetClient::new(env, &setup.token_address);
+    token_admin.mint(&payer, &REGISTRATION_FEE);
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    client.pay_verification_fee(&payer, &SorobanString::from_str(env, "org-8"), &setup.token_address);
+
+    // Tokens the contract holds for other purposes are not withdrawable fees
+    token_admin.mint(&setup.contract_id, &REGISTRATION_FEE);
+
+    let res = client.try_admin_withdraw_fees(&setup.admin, &setup.token_address, &(REGISTRATION_FEE + 1));
+    assert!(matches!(res, Err(Ok(Error::WithdrawalExceedsBalance))));
+
+    client.admin_withdraw_fees(&setup.admin, &setup.token_address, &REGISTRATION_FEE);
+    let res = client.try_admin_withdraw_fees(&setup.admin, &setup.token_address, &1);
+    assert!(matches!(res, Err(Ok(Error::WithdrawalExceedsBalance))));
+
+    let res = client.try_admin_withdraw_fees(&setup.admin, &setup.token_address, &0);
+    assert!(matches!(res, Err(Ok(Error::InvalidFeeAmount))));
+}
+
+#[test]
+fn refund_payment_returns_fee_and_blocks_onattest() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let payer = Address::generate(env);
+    let token_admin = token::StellarAssetClient::new(env, &setup.token_address);
+    let token_client = token::Client::new(env, &setup.token_address);
+    token_admin.mint(&payer, &REGISTRATION_FEE);
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    client.pay_verification_fee(&payer, &SorobanString::from_str(env, "org-9"), &setup.token_address);
+
+    let reason = SorobanString::from_str(env, "Failed due diligence");
+    let res = client.try_refund_payment(&Address::generate(env), &payer, &0, &reason);
+    assert!(matches!(res, Err(Ok(Error::NotAuthorized))));
+
+    assert_eq!(
+        client.refund_payment(&setup.admin, &payer, &0, &reason),
+        REGISTRATION_FEE
+    );
+    let events = env.events().all();
+    let refund_event = events
+        .iter()
+        .any(|(_, topics, _)| topics.get(0).and_then(|v| Symbol::try_from_val(env, &v).ok()) == Some(PAYMENT_REFUNDED));
+    assert!(refund_event, "PAYMENT_REFUNDED event missing: {:?}", events);
+
+    assert_eq!(token_client.balance(&payer), REGISTRATION_FEE);
+    assert!(!client.has_confirmed_payment(&payer));
+    assert_eq!(
+        client.get_payment_record(&payer).unwrap().status,
+        PaymentStatus::Refunded
+    );
+    assert_eq!(
+        client.get_payment_history(&payer, &0, &1).get(0).unwrap().status,
+        PaymentStatus::Refunded
+    );
+    assert_eq!(client.get_total_paid(&payer), 0);
+    assert_eq!(client.get_fee_ledger().unallocated(), 0);
+
+    let att = build_resolver_attestation(env, &setup.platform, &payer, "org-9");
+    assert!(matches!(
+        client.try_onattest(&att),
+        Err(Ok(ResolverError::ValidationFailed))
+    ));
+
+    let res = client.try_refund_payment(&setup.admin, &payer, &0, &reason);
+    assert!(matches!(res, Err(Ok(Error::PaymentAlreadyRefunded))));
+    let res = client.try_refund_payment(&setup.admin, &Address::generate(env), &0, &reason);
+    assert!(matches!(res, Err(Ok(Error::PaymentNotFound))));
+}
+
+#[test]
+fn refund_payment_rejects_used_or_withdrawn_fees() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let token_admin = token::StellarAssetClient::new(env, &setup.token_address);
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    let reason = SorobanString::from_str(env, "Rejected");
+
+    // A payment that registered an authority cannot be refunded
+    let registered = Address::generate(env);
+    token_admin.mint(&registered, &REGISTRATION_FEE);
+    client.pay_verification_fee(
+        &registered,
+        &SorobanString::from_str(env, "org-10"),
+        &setup.token_address,
+    );
+    client.approve_payment(&setup.admin, &registered);
+    let att = build_resolver_attestation(env, &setup.platform, &registered, "org-10");
+    client.onattest(&att);
+    client.onresolve(&att.uid, &setup.platform);
+    let res = client.try_refund_payment(&setup.admin, &registered, &0, &reason);
+    assert!(matches!(res, Err(Ok(Error::PaymentAlreadyUsed))));
+
+    // Fees already withdrawn by the treasurer are no longer in the contract
+    let applicant = Address::generate(env);
+    token_admin.mint(&applicant, &REGISTRATION_FEE);
+    client.pay_verification_fee(
+        &applicant,
+        &SorobanString::from_str(env, "org-11"),
+        &setup.token_address,
+    );
+    client.admin_withdraw_fees(&setup.admin, &setup.token_address, &(REGISTRATION_FEE * 2));
+    let res = client.try_refund_payment(&setup.admin, &applicant, &0, &reason);
+    assert!(matches!(res, Err(Ok(Error::WithdrawalExceedsBalance))));
+}
+
+#[test]
+fn refund_payment_returns_earlier_applications() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let token_client = token::Client::new(env, &setup.token_address);
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    let reason = SorobanString::from_str(env, "Rejected");
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, &setup.token_address).mint(&payer, &(REGISTRATION_FEE * 2));
+
+    // A rejected application, then a second one that registers the payer
+    client.pay_verification_fee(&payer, &SorobanString::from_str(env, "org-a"), &setup.token_address);
+    client.reject_payment(&setup.admin, &payer, &reason);
+    client.pay_verification_fee(&payer, &SorobanString::from_str(env, "org-b"), &setup.token_address);
+    client.approve_payment(&setup.admin, &payer);
+    let att = build_resolver_attestation(env, &setup.platform, &payer, "org-b");
+    client.onattest(&att);
+    client.onresolve(&att.uid, &setup.platform);
+
+    assert_eq!(
+        client.refund_payment(&setup.admin, &payer, &0, &reason),
+        REGISTRATION_FEE
+    );
+    assert_eq!(token_client.balance(&payer), REGISTRATION_FEE);
+    assert_eq!(
+        client.get_payment_history(&payer, &0, &1).get(0).unwrap().status,
+        PaymentStatus::Refunded
+    );
+    // The latest payment is untouched
+    let latest = client.get_payment_record(&payer).unwrap();
+    assert_eq!(latest.sequence, 1);
+    assert_eq!(latest.status, PaymentStatus::Approved);
+    assert_eq!(client.get_total_paid(&payer), REGISTRATION_FEE);
+
+    let res = client.try_refund_payment(&setup.admin, &payer, &1, &reason);
+    assert!(matches!(res, Err(Ok(Error::PaymentAlreadyUsed))));
+    let res = client.try_refund_payment(&setup.admin, &payer, &2, &reason);
+    assert!(matches!(res, Err(Ok(Error::PaymentNotFound))));
+}
+
+#[test]
+fn payment_review_gates_onattest() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let token_admin = token::StellarAssetClient::new(env, &setup.token_address);
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    let payer = Address::generate(env);
+    token_admin.mint(&payer, &REGISTRATION_FEE);
+    client.pay_verification_fee(
+        &payer,
+        &SorobanString::from_str(env, "org-review"),
+        &setup.token_address,
+    );
+    assert_eq!(
+        client.get_payment_record(&payer).unwrap().status,
+        PaymentStatus::Pending
+    );
+
+    // A pending payment does not pass the hooks
+    let att = build_resolver_attestation(env, &setup.platform, &payer, "org-review");
+    assert!(matches!(
+        client.try_onattest(&att),
+        Err(Ok(ResolverError::ValidationFailed))
+    ));
+
+    let res = client.try_approve_payment(&Address::generate(env), &payer);
+    assert!(matches!(res, Err(Ok(Error::NotAuthorized))));
+    let res = client.try_approve_payment(&setup.admin, &Address::generate(env));
+    assert!(matches!(res, Err(Ok(Error::PaymentNotFound))));
+
+    client.approve_payment(&setup.admin, &payer);
+    let approved_event =
+        env.events().all().iter().any(|(_, topics, _)| {
+            topics.get(0).and_then(|v| Symbol::try_from_val(env, &v).ok()) == Some(PAYMENT_APPROVED)
+        });
+    assert!(approved_event);
+    assert!(client.onattest(&att));
+
+    // Only pending payments are reviewed
+    let reason = SorobanString::from_str(env, "Changed our mind");
+    let res = client.try_reject_payment(&setup.admin, &payer, &reason);
+    assert!(matches!(res, Err(Ok(Error::InvalidPaymentTransition))));
+}
+
+#[test]
+fn rejected_payment_is_refunded() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let token_admin = token::StellarAssetClient::new(env, &setup.token_address);
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    let payer = Address::generate(env);
+    token_admin.mint(&payer, &REGISTRATION_FEE);
+    client.pay_verification_fee(
+        &payer,
+        &SorobanString::from_str(env, "org-reject"),
+        &setup.token_address,
+    );
+
+    let reason = SorobanString::from_str(env, "Sanctioned entity");
+    client.reject_payment(&setup.admin, &payer, &reason);
+    let rejected_event =
+        env.events().all().iter().any(|(_, topics, _)| {
+            topics.get(0).and_then(|v| Symbol::try_from_val(env, &v).ok()) == Some(PAYMENT_REJECTED)
+        });
+    assert!(rejected_event);
+    assert_eq!(
+        client.get_payment_record(&payer).unwrap().status,
+        PaymentStatus::Rejected
+    );
+    assert!(!client.has_confirmed_payment(&payer));
+    let res = client.try_approve_payment(&setup.admin, &payer);
+    assert!(matches!(res, Err(Ok(Error::InvalidPaymentTransition))));
+
+    client.refund_payment(&setup.admin, &payer, &0, &reason);
+    assert_eq!(
+        client.get_payment_record(&payer).unwrap().status,
+        PaymentStatus::Refunded
+    );
+    let res = client.try_approve_payment(&setup.admin, &payer);
+    assert!(matches!(res, Err(Ok(Error::InvalidPaymentTransition))));
+}
+
+#[test]
+fn fee_tokens_are_priced_and_withdrawn_separately() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    let usdc = env.register_stellar_asset_contract_v2(setup.admin.clone()).address();
+    let usdc_price: i128 = 25_0000000;
+
+    let res = client.try_set_fee_token(&Address::generate(env), &usdc, &usdc_price);
+    assert!(matches!(res, Err(Ok(Error::NotAuthorized))));
+    let res = client.try_set_fee_token(&setup.admin, &setup.token_address, &usdc_price);
+    assert!(matches!(res, Err(Ok(Error::InvalidFeeToken))));
+
+    client.set_fee_token(&setup.admin, &usdc, &usdc_price);
+    assert_eq!(client.get_fee_for_token(&usdc), usdc_price);
+    assert_eq!(client.get_fee_for_token(&setup.token_address), REGISTRATION_FEE);
+    assert_eq!(client.get_fee_tokens().get(usdc.clone()), Some(usdc_price));
+
+    // Each payer is charged the price of the token they pay in
+    let xlm_payer = Address::generate(env);
+    token::StellarAssetClient::new(env, &setup.token_address).mint(&xlm_payer, &REGISTRATION_FEE);
+    client.pay_verification_fee(
+        &xlm_payer,
+        &SorobanString::from_str(env, "org-xlm"),
+        &setup.token_address,
+    );
+    let usdc_payer = Address::generate(env);
+    token::StellarAssetClient::new(env, &usdc).mint(&usdc_payer, &usdc_price);
+    client.pay_verification_fee(&usdc_payer, &SorobanString::from_str(env, "org-usdc"), &usdc);
+
+    let record = client.get_payment_record(&usdc_payer).unwrap();
+    assert_eq!(record.token, usdc);
+    assert_eq!(record.amount_paid, usdc_price);
+    assert_eq!(client.get_fee_ledger().total_collected, REGISTRATION_FEE);
+    assert_eq!(client.get_token_fee_ledger(&usdc).total_collected, usdc_price);
+
+    // Balances do not mix across tokens
+    let res = client.try_admin_withdraw_fees(&setup.admin, &usdc, &(usdc_price + 1));
+    assert!(matches!(res, Err(Ok(Error::WithdrawalExceedsBalance))));
+    client.admin_withdraw_fees(&setup.admin, &usdc, &usdc_price);
+    assert_eq!(token::Client::new(env, &usdc).balance(&setup.admin), usdc_price);
+    assert_eq!(client.get_fee_ledger().unallocated(), REGISTRATION_FEE);
+
+    // A removed token is no longer accepted
+    client.remove_fee_token(&setup.admin, &usdc);
+    let res = client.try_get_fee_for_token(&usdc);
+    assert!(matches!(res, Err(Ok(Error::InvalidFeeToken))));
+    let res = client.try_remove_fee_token(&setup.admin, &usdc);
+    assert!(matches!(res, Err(Ok(Error::InvalidFeeToken))));
+}
+
+#[test]
+fn refund_is_paid_in_the_original_token() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    let usdc = env.register_stellar_asset_contract_v2(setup.admin.clone()).address();
+    let usdc_price: i128 = 25_0000000;
+    client.set_fee_token(&setup.admin, &usdc, &usdc_price);
+
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, &usdc).mint(&payer, &usdc_price);
+    client.pay_verification_fee(&payer, &SorobanString::from_str(env, "org-usdc"), &usdc);
+
+    // Still refundable after the token stops being accepted
+    client.remove_fee_token(&setup.admin, &usdc);
+    let reason = SorobanString::from_str(env, "Rejected");
+    assert_eq!(client.refund_payment(&setup.admin, &payer, &0, &reason), usdc_price);
+    assert_eq!(token::Client::new(env, &usdc).balance(&payer), usdc_price);
+    assert_eq!(client.get_token_fee_ledger(&usdc).unallocated(), 0);
+}
+
+#[test]
+fn register_authority_fee_is_credited_to_ledger() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let caller = Address::generate(env);
+    token::StellarAssetClient::new(env, &setup.token_address).mint(&caller, &REGISTRATION_FEE);
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+
+    let nominee = Address::generate(env);
+    client.register_authority(&caller, &nominee, &test_profile(env, "meta"));
+    // Escrowed until the nominee accepts
+    assert_eq!(client.get_fee_ledger().total_collected, 0);
+    let res = client.try_admin_withdraw_fees(&setup.admin, &setup.token_address, &REGISTRATION_FEE);
+    assert!(matches!(res, Err(Ok(Error::WithdrawalExceedsBalance))));
+
+    client.accept_authority_nomination(&nominee);
+    assert_eq!(client.get_fee_ledger().total_collected, REGISTRATION_FEE);
+
+    client.admin_withdraw_fees(&setup.admin, &setup.token_address, &REGISTRATION_FEE);
+    assert_eq!(client.get_fee_ledger().total_withdrawn, REGISTRATION_FEE);
+}
+
+#[test]
+fn payment_expires_after_validity_and_grace_period() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    let year = 365 * 24 * 60 * 60;
+    let grace = 30 * 24 * 60 * 60;
+    client.set_validity_period(&setup.admin, &Some(year), &grace);
+
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, &setup.token_address).mint(&payer, &REGISTRATION_FEE);
+    client.pay_verification_fee(&payer, &SorobanString::from_str(env, "org-exp"), &setup.token_address);
+    client.approve_payment(&setup.admin, &payer);
+    assert_eq!(client.get_payment_record(&payer).unwrap().expires_at, Some(year));
+
+    let att = build_resolver_attestation(env, &setup.platform, &payer, "org-exp");
+    assert!(client.onattest(&att));
+    client.onresolve(&att.uid, &setup.platform);
+    assert_eq!(client.get_authority_data(&payer).unwrap().expires_at, Some(year));
+
+    // Still usable during the grace period
+    env.ledger().with_mut(|li| li.timestamp = year + grace - 1);
+    assert!(client.has_confirmed_payment(&payer));
+    assert!(client.is_authority(&payer));
+
+    env.ledger().with_mut(|li| li.timestamp = year + grace);
+    assert!(!client.has_confirmed_payment(&payer));
+    assert!(!client.is_authority(&payer));
+    let res = client.try_onattest(&build_resolver_attestation(env, &setup.platform, &payer, "org-exp"));
+    assert!(matches!(res, Err(Ok(ResolverError::ValidationFailed))));
+}
+
+#[test]
+fn set_validity_period_requires_owner_and_positive_period() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    assert_eq!(client.get_validity_period(), None);
+    assert_eq!(client.get_grace_period(), 0);
+
+    let res = client.try_set_validity_period(&Address::generate(env), &Some(3600), &0);
+    assert!(matches!(res, Err(Ok(Error::NotAuthorized))));
+    let res = client.try_set_validity_period(&setup.admin, &Some(0), &0);
+    assert!(matches!(res, Err(Ok(Error::InvalidValidityPeriod))));
+
+    client.set_validity_period(&setup.admin, &Some(3600), &600);
+    let events = env.events().all();
+    let updated = events
+        .iter()
+        .any(|(_, topics, _)| topics.get(0).and_then(|v| Symbol::try_from_val(env, &v).ok()) == Some(VALIDITY_UPDATED));
+    assert!(updated, "VALIDITY_UPDATED event missing: {:?}", events);
+    assert_eq!(client.get_validity_period(), Some(3600));
+    assert_eq!(client.get_grace_period(), 600);
+
+    // Clearing the period makes new registrations permanent again
+    client.set_validity_period(&setup.admin, &None, &0);
+    assert_eq!(client.get_validity_period(), None);
+}
+
+#[test]
+fn onattest_rejects_schema_above_attested_tier() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, &setup.token_address).mint(&payer, &REGISTRATION_FEE);
+    client.pay_verification_fee(&payer, &SorobanString::from_str(env, "org-tier"), &setup.token_address);
+    client.approve_payment(&setup.admin, &payer);
+
+    let att = build_resolver_attestation(env, &setup.platform, &payer, "org-tier");
+    let rules = SchemaRules {
+        allowed_authorities: soroban_sdk::Vec::new(env),
+        min_level: VerificationLevel::Regulated,
+        levy_amount: None,
+        levy_recipient: None,
+        revocable: true,
+        max_expiration: None,
+    };
+    client.admin_register_schema(&setup.admin, &att.schema_uid, &rules);
+    let res = client.try_onattest(&att);
+    assert!(matches!(res, Err(Ok(ResolverError::NotAuthorized))));
+
+    // The granted tier counts, not the platform attester's own level
+    let mut payload = verification_payload(env, "org-tier");
+    payload.tier = 2;
+    let mut regulated = build_resolver_attestation(env, &setup.platform, &payer, "org-tier");
+    regulated.schema_uid = att.schema_uid.clone();
+    regulated.data = payload.to_xdr(env);
+    assert!(client.onattest(&regulated));
+
+    client.set_schema_min_level(&setup.admin, &att.schema_uid, &VerificationLevel::Basic);
+    assert!(client.onattest(&att));
+}
+
+#[test]
+fn onattest_charges_schema_levy() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    let token_admin = token::StellarAssetClient::new(env, &setup.token_address);
+    let payer = Address::generate(env);
+    token_admin.mint(&payer, &(REGISTRATION_FEE + REWARD_AMOUNT));
+    client.pay_verification_fee(&payer, &SorobanString::from_str(env, "org-levy"), &setup.token_address);
+    client.approve_payment(&setup.admin, &payer);
+
+    let recipient = Address::generate(env);
+    client.admin_register_authority(&setup.admin, &recipient, &test_profile(env, "Schema Owner"));
+    let att = build_resolver_attestation(env, &setup.platform, &payer, "org-levy");
+    let rules = SchemaRules {
+        allowed_authorities: soroban_sdk::Vec::new(env),
+        min_level: VerificationLevel::Basic,
+        levy_amount: Some(REWARD_AMOUNT),
+        levy_recipient: Some(recipient.clone()),
+        revocable: true,
+        max_expiration: None,
+    };
+    client.admin_register_schema(&setup.admin, &att.schema_uid, &rules);
+
+    assert!(client.onattest(&att));
+    assert_eq!(
+        client.get_collected_levies(&recipient, &setup.token_address),
+        REWARD_AMOUNT
+    );
+    // The organization being verified pays the levy, not the platform attester
+    assert_eq!(token::Client::new(env, &setup.token_address).balance(&payer), 0);
+}
+
+#[test]
+fn onattest_levy_requires_the_recipients_authorization() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, &setup.token_address).mint(&payer, &(REGISTRATION_FEE + REWARD_AMOUNT));
+    client.pay_verification_fee(
+        &payer,
+        &SorobanString::from_str(env, "org-cosign"),
+        &setup.token_address,
+    );
+    client.approve_payment(&setup.admin, &payer);
+    let recipient = Address::generate(env);
+    client.admin_register_authority(&setup.admin, &recipient, &test_profile(env, "Schema Owner"));
+    let att = build_resolver_attestation(env, &setup.platform, &payer, "org-cosign");
+    let rules = SchemaRules {
+        allowed_authorities: soroban_sdk::Vec::new(env),
+        min_level: VerificationLevel::Basic,
+        levy_amount: Some(REWARD_AMOUNT),
+        levy_recipient: Some(recipient.clone()),
+        revocable: true,
+        max_expiration: None,
+    };
+    client.admin_register_schema(&setup.admin, &att.schema_uid, &rules);
+
+    // The protocol's authorization alone cannot charge the organization
+    mock_hook_auth(
+        env,
+        &setup.contract_id,
+        &setup.protocol,
+        "onattest",
+        (att.clone(),).into_val(env),
+    );
+    assert!(client.try_onattest(&att).is_err());
+    assert_eq!(client.get_collected_levies(&recipient, &setup.token_address), 0);
+
+    // The organization co-signs the attestation and the levy transfer
+    let onattest_invoke = MockAuthInvoke {
+        contract: &setup.contract_id,
+        fn_name: "onattest",
+        args: (att.clone(),).into_val(env),
+        sub_invokes: &[],
+    };
+    let transfer_invoke = MockAuthInvoke {
+        contract: &setup.token_address,
+        fn_name: "transfer",
+        args: (payer.clone(), setup.contract_id.clone(), REWARD_AMOUNT).into_val(env),
+        sub_invokes: &[],
+    };
+    env.mock_auths(&[
+        MockAuth {
+            address: &setup.protocol,
+            invoke: &onattest_invoke,
+        },
+        MockAuth {
+            address: &payer,
+            invoke: &MockAuthInvoke {
+                sub_invokes: std::slice::from_ref(&transfer_invoke),
+                ..onattest_invoke.clone()
+            },
+        },
+    ]);
+    assert!(client.onattest(&att));
+    assert_eq!(
+        client.get_collected_levies(&recipient, &setup.token_address),
+        REWARD_AMOUNT
+    );
+}
+
+#[test]
+fn paused_contract_blocks_hooks_and_payments() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = AuthorityResolverContractClient::new(env, &setup.contract_id);
+    let token_admin = token::StellarAssetClient::new(env, &setup.token_address);
+    let payer = Address::generate(env);
+    token_admin.mint(&payer, &(REGISTRATION_FEE * 2));
+    let ref_id = SorobanString::from_str(env, "org-paused");
+    client.pay_verification_fee(&payer, &ref_id, &setup.token_address);
+    client.approve_payment(&setup.admin, &payer);
+    let att = build_resolver_attestation(env, &setup.platform, &payer, "org-paused");
+    assert!(client.onattest(&att));
+
+    client.pause(&setup.admin);
+    assert!(client.is_paused());
+
+    let res = client.try_onattest(&build_resolver_attestation(env, &setup.platform, &payer, "org-paused"));
+    assert!(matches!(res, Err(Ok(ResolverError::Paused))));
+    let res = client.try_onresolve(&att.uid, &setup.platform);
+    assert!(matches!(res, Err(Ok(ResolverError::Paused))));
+    let res = client.try_pay_verification_fee(&payer, &ref_id, &setup.token_address);
+    assert!(matches!(res, Err(Ok(Error::ContractPaused))));
+    let res = client.try_admin_withdraw_fees(&setup.admin, &setup.token_address, &REGISTRATION_FEE);
+    assert!(matches!(res, Err(Ok(Error::ContractPaused))));
+
+    // Read-only getters keep working
+    assert!(client.has_confirmed_payment(&payer));
+    assert_eq!(client.get_fee_ledger().total_collected, REGISTRATION_FEE);
+
+    client.unpause(&setup.admin);
+    client.onresolve(&att.uid, &setup.platform);
+    assert!(client.is_authority(&payer));
 }
 
 #[test]
@@ -49,6 +49,9 @@ pub enum ResolverError {
     StakeRequired = 6,
     ValidationFailed = 7,
     CustomError = 8,
+    Paused = 9,           // resolver is paused; hooks and fund transfers are stopped
+    NotPaused = 10,       // unpause called on a running resolver
+    AlreadyMigrated = 11, // storage is already at the version of the running code
 }
 
 /// Standard Resolver Interface that all resolvers must implement
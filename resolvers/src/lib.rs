@@ -10,6 +10,11 @@
 /// like `ResolverAttestationData`, `ResolverMetadata`, and standardized error types.
 pub mod interface;
 
+/// Emergency pause, pauser and upgrade handling shared by the resolver contracts.
+/// Resolvers check their own admin, then call in here so the state, errors and
+/// events are the same across them.
+pub mod lifecycle;
+
 // Re-export core interface types
 pub use interface::{
     ResolverAttestationData, ResolverError, ResolverInterface, ResolverMetadata, ResolverType,
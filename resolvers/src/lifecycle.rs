@@ -0,0 +1,123 @@
+//! Emergency pause, pauser and in-place upgrade handling shared by the resolver contracts.
+//!
+//! Each resolver keeps its own admin and checks it before calling in here. The keys
+//! below encode like the `Paused`, `Pauser` and `Version` variants the resolvers kept
+//! in their own `DataKey` before this module existed, so stored state carries over.
+use crate::interface::ResolverError;
+use soroban_sdk::{contracttype, Address, BytesN, Env, String};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LifecycleKey {
+    Paused,
+    Pauser, // Address allowed to pause next to the admin
+    Version,
+}
+
+/// Check whether the resolver is paused
+pub fn is_paused(env: &Env) -> bool {
+    env.storage().instance().get(&LifecycleKey::Paused).unwrap_or(false)
+}
+
+/// Fails with `ResolverError::Paused` while the resolver is paused
+pub fn require_not_paused(env: &Env) -> Result<(), ResolverError> {
+    if is_paused(env) {
+        return Err(ResolverError::Paused);
+    }
+    Ok(())
+}
+
+/// Requires `caller` to be the resolver's admin or its configured pauser
+pub fn require_pauser(env: &Env, admin: &Address, caller: &Address) -> Result<(), ResolverError> {
+    caller.require_auth();
+
+    let pauser: Option<Address> = env.storage().instance().get(&LifecycleKey::Pauser);
+    if caller != admin && pauser.as_ref() != Some(caller) {
+        return Err(ResolverError::NotAuthorized);
+    }
+
+    Ok(())
+}
+
+/// Set or clear the pauser; the caller must already be checked as admin
+pub fn set_pauser(env: &Env, pauser: Option<Address>) {
+    match &pauser {
+        Some(pauser) => env.storage().instance().set(&LifecycleKey::Pauser, pauser),
+        None => env.storage().instance().remove(&LifecycleKey::Pauser),
+    }
+    extend_instance_ttl(env);
+
+    env.events().publish((String::from_str(env, "PAUSER_UPDATED"),), pauser);
+}
+
+/// Pause the resolver; `caller` must already be checked with `require_pauser`
+pub fn pause(env: &Env, caller: &Address) -> Result<(), ResolverError> {
+    require_not_paused(env)?;
+
+    env.storage().instance().set(&LifecycleKey::Paused, &true);
+    extend_instance_ttl(env);
+
+    env.events().publish((String::from_str(env, "PAUSED"),), caller);
+
+    Ok(())
+}
+
+/// Resume the resolver; `caller` must already be checked with `require_pauser`
+///
+/// Fails with `ResolverError::NotPaused` if the resolver is running.
+pub fn unpause(env: &Env, caller: &Address) -> Result<(), ResolverError> {
+    if !is_paused(env) {
+        return Err(ResolverError::NotPaused);
+    }
+
+    env.storage().instance().set(&LifecycleKey::Paused, &false);
+    extend_instance_ttl(env);
+
+    env.events().publish((String::from_str(env, "UNPAUSED"),), caller);
+
+    Ok(())
+}
+
+/// Get the storage layout version, zero for deployments that predate versioning
+pub fn get_version(env: &Env) -> u32 {
+    env.storage().instance().get(&LifecycleKey::Version).unwrap_or(0)
+}
+
+/// Record the storage layout version, e.g. at deployment
+pub fn set_version(env: &Env, version: u32) {
+    env.storage().instance().set(&LifecycleKey::Version, &version);
+}
+
+/// Replace the contract code with an uploaded WASM; the caller must already be checked as admin
+pub fn upgrade(env: &Env, new_wasm_hash: BytesN<32>) {
+    env.events()
+        .publish((String::from_str(env, "UPGRADED"),), (&new_wasm_hash, get_version(env)));
+
+    env.deployer().update_current_contract_wasm(new_wasm_hash);
+}
+
+/// Record `current_version` as the storage version; the caller must already be
+/// checked as admin
+///
+/// Fails with `ResolverError::AlreadyMigrated` once storage is at `current_version`,
+/// so a version is migrated to exactly once. Returns the new version.
+pub fn migrate(env: &Env, current_version: u32) -> Result<u32, ResolverError> {
+    let from_version = get_version(env);
+    if from_version >= current_version {
+        return Err(ResolverError::AlreadyMigrated);
+    }
+
+    set_version(env, current_version);
+    extend_instance_ttl(env);
+
+    env.events()
+        .publish((String::from_str(env, "MIGRATED"),), (from_version, current_version));
+
+    Ok(current_version)
+}
+
+fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(env.storage().max_ttl() - 100, env.storage().max_ttl());
+}
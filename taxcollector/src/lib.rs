@@ -1,5 +1,5 @@
 #![no_std]
-use resolvers::{ResolverAttestationData, ResolverError, ResolverInterface, ResolverMetadata, ResolverType};
+use resolvers::{lifecycle, ResolverAttestationData, ResolverError, ResolverInterface, ResolverMetadata, ResolverType};
 use soroban_sdk::{contract, contractimpl, contracttype, token, Address, BytesN, Env, String};
 
 #[contracttype]
@@ -14,6 +14,9 @@ pub enum DataKey {
     CollectedFees,
 }
 
+/// Version of the storage layout this code expects, recorded by `migrate`
+pub const CONTRACT_VERSION: u32 = 1;
+
 /// FeeCollectionResolver - Collects XLM fees for attestations
 #[contract]
 pub struct FeeCollectionResolver;
@@ -54,6 +57,7 @@ impl FeeCollectionResolver {
         env.storage().instance().set(&DataKey::AttestationFee, &attestation_fee);
         env.storage().instance().set(&DataKey::FeeRecipient, &fee_recipient);
         env.storage().instance().set(&DataKey::TotalCollected, &0i128);
+        lifecycle::set_version(&env, CONTRACT_VERSION);
         env.storage().instance().set(&DataKey::Initialized, &true);
 
         env.storage()
@@ -100,6 +104,7 @@ impl FeeCollectionResolver {
         env.storage().instance().set(&DataKey::AttestationFee, &attestation_fee);
         env.storage().instance().set(&DataKey::FeeRecipient, &fee_recipient);
         env.storage().instance().set(&DataKey::TotalCollected, &0i128);
+        lifecycle::set_version(&env, CONTRACT_VERSION);
         env.storage().instance().set(&DataKey::Initialized, &true);
 
         env.storage()
@@ -147,6 +152,7 @@ impl FeeCollectionResolver {
     /// Withdraw collected fees (fee recipient only)
     pub fn withdraw_fees(env: Env, recipient: Address) -> Result<(), ResolverError> {
         recipient.require_auth();
+        lifecycle::require_not_paused(&env)?;
 
         let fee_recipient: Address = env
             .storage()
@@ -187,6 +193,54 @@ impl FeeCollectionResolver {
         Ok(())
     }
 
+    /// Set or clear the address allowed to pause the resolver next to the admin (admin only)
+    pub fn set_pauser(env: Env, admin: Address, pauser: Option<Address>) -> Result<(), ResolverError> {
+        Self::require_admin(&env, &admin)?;
+        lifecycle::set_pauser(&env, pauser);
+        Ok(())
+    }
+
+    /// Pause the resolver hooks and fund transfers (admin or pauser)
+    pub fn pause(env: Env, caller: Address) -> Result<(), ResolverError> {
+        lifecycle::require_pauser(&env, &Self::get_admin(&env)?, &caller)?;
+        lifecycle::pause(&env, &caller)
+    }
+
+    /// Resume the resolver (admin or pauser)
+    pub fn unpause(env: Env, caller: Address) -> Result<(), ResolverError> {
+        lifecycle::require_pauser(&env, &Self::get_admin(&env)?, &caller)?;
+        lifecycle::unpause(&env, &caller)
+    }
+
+    /// Check whether the resolver is paused
+    pub fn is_paused(env: Env) -> bool {
+        lifecycle::is_paused(&env)
+    }
+
+    /// Replace the contract code with an uploaded WASM (admin only)
+    ///
+    /// Call `migrate` afterwards so the new code can update stored data.
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), ResolverError> {
+        Self::require_admin(&env, &admin)?;
+        lifecycle::upgrade(&env, new_wasm_hash);
+        Ok(())
+    }
+
+    /// Run the storage migrations of the current code, once per version (admin only)
+    ///
+    /// Fee settings and collected totals are stored as unversioned deployments left
+    /// them; version 1 only introduced the optional pause flag and pauser, so the
+    /// migration to it just records the version. Returns the new version.
+    pub fn migrate(env: Env, admin: Address) -> Result<u32, ResolverError> {
+        Self::require_admin(&env, &admin)?;
+        lifecycle::migrate(&env, CONTRACT_VERSION)
+    }
+
+    /// Get the storage layout version, zero for deployments that predate versioning
+    pub fn get_version(env: Env) -> u32 {
+        lifecycle::get_version(&env)
+    }
+
     /// Get total fees collected
     pub fn get_total_collected(env: Env) -> i128 {
         env.storage().instance().get(&DataKey::TotalCollected).unwrap_or(0)
@@ -201,19 +255,20 @@ impl FeeCollectionResolver {
     fn require_admin(env: &Env, caller: &Address) -> Result<(), ResolverError> {
         caller.require_auth();
 
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(ResolverError::CustomError)?;
-
-        if caller != &admin {
+        if caller != &Self::get_admin(env)? {
             return Err(ResolverError::NotAuthorized);
         }
 
         Ok(())
     }
 
+    fn get_admin(env: &Env) -> Result<Address, ResolverError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(ResolverError::CustomError)
+    }
+
     /// Extends the TTL of instance storage to prevent expiration.
     /// Should be called on any method that relies on instance storage.
     fn extend_instance_ttl(env: &Env) {
@@ -227,6 +282,8 @@ impl FeeCollectionResolver {
 impl ResolverInterface for FeeCollectionResolver {
     /// Collect fee before attestation
     fn onattest(env: Env, attestation: ResolverAttestationData) -> Result<bool, ResolverError> {
+        lifecycle::require_not_paused(&env)?;
+
         // Get fee configuration
         let attestation_fee: i128 = env.storage().instance().get(&DataKey::AttestationFee).unwrap_or(0);
 
@@ -286,11 +343,11 @@ impl ResolverInterface for FeeCollectionResolver {
 
     /// No validation needed for revocations
     fn onresolve(
-        _env: Env,
+        env: Env,
         _attestation_uid: BytesN<32>,
         _attester: Address,
     ) -> core::result::Result<(), ResolverError> {
-        Ok(())
+        lifecycle::require_not_paused(&env)
     }
 
     fn metadata(env: Env) -> ResolverMetadata {
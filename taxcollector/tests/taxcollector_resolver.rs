@@ -5,8 +5,8 @@ use soroban_sdk::{
     token, Address, Bytes, BytesN, Env,
 };
 
-use resolvers::{ResolverAttestationData, ResolverType};
-use taxcollector::{FeeCollectionResolver, FeeCollectionResolverClient};
+use resolvers::{lifecycle::LifecycleKey, ResolverAttestationData, ResolverError, ResolverType};
+use taxcollector::{FeeCollectionResolver, FeeCollectionResolverClient, CONTRACT_VERSION};
 
 const ATTESTATION_FEE: i128 = 5_0000000; // 5 tokens per attestation
 
@@ -410,3 +410,95 @@ fn test_change_recipient_mid_collection() {
     assert_eq!(client.get_collected_fees(&new_recipient), ATTESTATION_FEE);
     assert_eq!(client.get_total_collected(), ATTESTATION_FEE * 2);
 }
+
+// ============================================================================
+// Pause Tests
+// ============================================================================
+
+#[test]
+fn test_pause_blocks_hooks_and_withdrawals() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = FeeCollectionResolverClient::new(env, &setup.contract_id);
+
+    let attester = Address::generate(env);
+    let token_admin = token::StellarAssetClient::new(env, &setup.fee_token);
+    token_admin.mint(&attester, &(ATTESTATION_FEE * 2));
+    client.onattest(&build_attestation(env, &attester));
+
+    client.pause(&setup.admin);
+    assert!(client.is_paused());
+
+    let result = client.try_onattest(&build_attestation(env, &attester));
+    assert_eq!(result.err().unwrap(), Ok(ResolverError::Paused));
+    let result = client.try_onresolve(&BytesN::random(env), &attester);
+    assert_eq!(result.err().unwrap(), Ok(ResolverError::Paused));
+    let result = client.try_withdraw_fees(&setup.fee_recipient);
+    assert_eq!(result.err().unwrap(), Ok(ResolverError::Paused));
+
+    // Getters keep working
+    assert_eq!(client.get_collected_fees(&setup.fee_recipient), ATTESTATION_FEE);
+
+    client.unpause(&setup.admin);
+    assert!(!client.is_paused());
+    client.withdraw_fees(&setup.fee_recipient);
+    client.onattest(&build_attestation(env, &attester));
+}
+
+#[test]
+fn test_pauser_can_pause_and_unpause() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let client = FeeCollectionResolverClient::new(env, &setup.contract_id);
+    let pauser = Address::generate(env);
+
+    let result = client.try_pause(&pauser);
+    assert_eq!(result.err().unwrap(), Ok(ResolverError::NotAuthorized));
+
+    client.set_pauser(&setup.admin, &Some(pauser.clone()));
+    client.pause(&pauser);
+    let result = client.try_pause(&pauser);
+    assert_eq!(result.err().unwrap(), Ok(ResolverError::Paused));
+
+    client.unpause(&pauser);
+    let result = client.try_unpause(&pauser);
+    assert_eq!(result.err().unwrap(), Ok(ResolverError::NotPaused));
+
+    // Clearing the pauser removes its rights
+    client.set_pauser(&setup.admin, &None);
+    let result = client.try_pause(&pauser);
+    assert_eq!(result.err().unwrap(), Ok(ResolverError::NotAuthorized));
+}
+
+// ============================================================================
+// Upgrade Tests
+// ============================================================================
+
+#[test]
+fn test_upgrade_non_admin_fails() {
+    let setup = setup_env();
+    let client = FeeCollectionResolverClient::new(&setup.env, &setup.contract_id);
+
+    let non_admin = Address::generate(&setup.env);
+    let result = client.try_upgrade(&non_admin, &BytesN::random(&setup.env));
+    assert_eq!(result.err().unwrap(), Ok(ResolverError::NotAuthorized));
+}
+
+#[test]
+fn test_migrate_runs_once_per_version() {
+    let setup = setup_env();
+    let client = FeeCollectionResolverClient::new(&setup.env, &setup.contract_id);
+
+    assert_eq!(client.get_version(), CONTRACT_VERSION);
+    let result = client.try_migrate(&setup.admin);
+    assert_eq!(result.err().unwrap(), Ok(ResolverError::AlreadyMigrated));
+
+    // Simulate a deployment that predates versioning
+    setup.env.as_contract(&setup.contract_id, || {
+        setup.env.storage().instance().remove(&LifecycleKey::Version);
+    });
+    assert_eq!(client.get_version(), 0);
+
+    assert_eq!(client.migrate(&setup.admin), CONTRACT_VERSION);
+    assert_eq!(client.get_version(), CONTRACT_VERSION);
+}